@@ -162,6 +162,22 @@ pub enum AlterTableOperation {
         // See `AttachPartition` for more details
         partition: Partition,
     },
+    /// `ATTACH PARTITION <partition_name> { FOR VALUES <partition_bound_spec> | DEFAULT }`
+    ///
+    /// Note: this is a PostgreSQL-specific operation, please refer to
+    /// [PostgreSQL](https://www.postgresql.org/docs/current/sql-altertable.html)
+    AttachTablePartition {
+        partition_name: ObjectName,
+        for_values: ForValues,
+    },
+    /// `DETACH PARTITION <partition_name> [ CONCURRENTLY | FINALIZE ]`
+    ///
+    /// Note: this is a PostgreSQL-specific operation, please refer to
+    /// [PostgreSQL](https://www.postgresql.org/docs/current/sql-altertable.html)
+    DetachTablePartition {
+        partition_name: ObjectName,
+        modifier: Option<DetachPartitionModifier>,
+    },
     /// `FREEZE PARTITION <partition_expr>`
     /// Note: this is a ClickHouse-specific operation, please refer to
     /// [ClickHouse](https://clickhouse.com/docs/en/sql-reference/statements/alter/partition#freeze-partition)
@@ -319,6 +335,18 @@ pub enum AlterTableOperation {
     DropClusteringKey,
     SuspendRecluster,
     ResumeRecluster,
+    /// `SET TAG <tag_name> = '<tag_value>' [, ...]`
+    ///
+    /// Note: this is Snowflake specific <https://docs.snowflake.com/en/sql-reference/sql/alter-table>
+    SetTags {
+        tags: Vec<Tag>,
+    },
+    /// `UNSET TAG <tag_name> [, ...]`
+    ///
+    /// Note: this is Snowflake specific <https://docs.snowflake.com/en/sql-reference/sql/alter-table>
+    UnsetTags {
+        keys: Vec<ObjectName>,
+    },
     /// `ALGORITHM [=] { DEFAULT | INSTANT | INPLACE | COPY }`
     ///
     /// [MySQL]-specific table alter algorithm.
@@ -662,6 +690,22 @@ impl fmt::Display for AlterTableOperation {
             AlterTableOperation::DetachPartition { partition } => {
                 write!(f, "DETACH {partition}")
             }
+            AlterTableOperation::AttachTablePartition {
+                partition_name,
+                for_values,
+            } => {
+                write!(f, "ATTACH PARTITION {partition_name} {for_values}")
+            }
+            AlterTableOperation::DetachTablePartition {
+                partition_name,
+                modifier,
+            } => {
+                write!(f, "DETACH PARTITION {partition_name}")?;
+                if let Some(modifier) = modifier {
+                    write!(f, " {modifier}")?;
+                }
+                Ok(())
+            }
             AlterTableOperation::EnableAlwaysRule { name } => {
                 write!(f, "ENABLE ALWAYS RULE {name}")
             }
@@ -784,6 +828,12 @@ impl fmt::Display for AlterTableOperation {
                 write!(f, "RESUME RECLUSTER")?;
                 Ok(())
             }
+            AlterTableOperation::SetTags { tags } => {
+                write!(f, "SET TAG {}", display_comma_separated(tags))
+            }
+            AlterTableOperation::UnsetTags { keys } => {
+                write!(f, "UNSET TAG {}", display_comma_separated(keys))
+            }
             AlterTableOperation::AutoIncrement { equals, value } => {
                 write!(
                     f,
@@ -1938,6 +1988,10 @@ impl fmt::Display for ColumnOption {
                         write!(f, "AS ({expr}){modifier}")?;
                     }
                     Ok(())
+                } else if *generated_as == GeneratedAs::RowStart {
+                    write!(f, "GENERATED ALWAYS AS ROW START")
+                } else if *generated_as == GeneratedAs::RowEnd {
+                    write!(f, "GENERATED ALWAYS AS ROW END")
                 } else {
                     // Like Postgres - generated from sequence
                     let when = match generated_as {
@@ -1945,6 +1999,8 @@ impl fmt::Display for ColumnOption {
                         GeneratedAs::ByDefault => "BY DEFAULT",
                         // ExpStored goes with an expression, handled above
                         GeneratedAs::ExpStored => unreachable!(),
+                        // Row periods are handled above
+                        GeneratedAs::RowStart | GeneratedAs::RowEnd => unreachable!(),
                     };
                     write!(f, "GENERATED {when} AS IDENTITY")?;
                     if sequence_options.is_some() {
@@ -1994,6 +2050,31 @@ pub enum GeneratedAs {
     Always,
     ByDefault,
     ExpStored,
+    /// `GENERATED ALWAYS AS ROW START` for system-versioned tables
+    RowStart,
+    /// `GENERATED ALWAYS AS ROW END` for system-versioned tables
+    RowEnd,
+}
+
+/// `PERIOD FOR SYSTEM_TIME (start, end)` in the column list of a
+/// system-versioned `CREATE TABLE`.
+///
+/// [MariaDB](https://mariadb.com/kb/en/system-versioned-tables/)
+/// [MsSql](https://learn.microsoft.com/en-us/sql/relational-databases/tables/creating-a-system-versioned-temporal-table)
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct PeriodForSystemTime {
+    /// The `GENERATED ALWAYS AS ROW START` column
+    pub start: Ident,
+    /// The `GENERATED ALWAYS AS ROW END` column
+    pub end: Ident,
+}
+
+impl fmt::Display for PeriodForSystemTime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PERIOD FOR SYSTEM_TIME ({}, {})", self.start, self.end)
+    }
 }
 
 /// `GeneratedExpressionMode`s are modifiers that follow an expression in a `generated`.
@@ -2294,6 +2375,268 @@ impl fmt::Display for ClusteredBy {
     }
 }
 
+/// PostgreSQL declarative partitioning strategy of a partitioned table.
+/// Syntax: `PARTITION BY { RANGE | LIST | HASH } ( { column_name | ( expression ) } [, ...] )`
+///
+/// [PostgreSQL](https://www.postgresql.org/docs/current/sql-createtable.html)
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum PartitionStrategy {
+    Range(Vec<Expr>),
+    List(Vec<Expr>),
+    Hash(Vec<Expr>),
+}
+
+impl fmt::Display for PartitionStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PartitionStrategy::Range(exprs) => {
+                write!(f, "RANGE ({})", display_comma_separated(exprs))
+            }
+            PartitionStrategy::List(exprs) => {
+                write!(f, "LIST ({})", display_comma_separated(exprs))
+            }
+            PartitionStrategy::Hash(exprs) => {
+                write!(f, "HASH ({})", display_comma_separated(exprs))
+            }
+        }
+    }
+}
+
+/// The `LIKE` clause of a `CREATE TABLE` statement.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum CreateTableLikeKind {
+    /// `CREATE TABLE t (LIKE source [ { INCLUDING | EXCLUDING } option ... ])`
+    ///
+    /// [PostgreSQL](https://www.postgresql.org/docs/current/sql-createtable.html)
+    Parenthesized(CreateTableLike),
+    /// `CREATE TABLE t LIKE source`
+    ///
+    /// [Hive](https://cwiki.apache.org/confluence/display/Hive/LanguageManual+DDL#LanguageManualDDL-CreateTableLike)
+    /// [Snowflake](https://docs.snowflake.com/en/sql-reference/sql/create-table#create-table-like)
+    Plain(CreateTableLike),
+}
+
+impl fmt::Display for CreateTableLikeKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CreateTableLikeKind::Parenthesized(like) => write!(f, "({like})"),
+            CreateTableLikeKind::Plain(like) => write!(f, "{like}"),
+        }
+    }
+}
+
+/// The source table of a `CREATE TABLE ... LIKE` along with the
+/// properties to copy from it.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct CreateTableLike {
+    pub name: ObjectName,
+    pub options: Vec<CreateTableLikeOption>,
+}
+
+impl fmt::Display for CreateTableLike {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "LIKE {}", self.name)?;
+        if !self.options.is_empty() {
+            write!(f, " {}", display_separated(&self.options, " "))?;
+        }
+        Ok(())
+    }
+}
+
+/// An `INCLUDING` or `EXCLUDING` option of a PostgreSQL `CREATE TABLE ... (LIKE ...)`.
+///
+/// [PostgreSQL](https://www.postgresql.org/docs/current/sql-createtable.html)
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum CreateTableLikeOption {
+    Including(CreateTableLikeProperty),
+    Excluding(CreateTableLikeProperty),
+}
+
+impl fmt::Display for CreateTableLikeOption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CreateTableLikeOption::Including(property) => write!(f, "INCLUDING {property}"),
+            CreateTableLikeOption::Excluding(property) => write!(f, "EXCLUDING {property}"),
+        }
+    }
+}
+
+/// The table property copied, or not, by a [`CreateTableLikeOption`].
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum CreateTableLikeProperty {
+    All,
+    Comments,
+    Compression,
+    Constraints,
+    Defaults,
+    Generated,
+    Identity,
+    Indexes,
+    Statistics,
+    Storage,
+}
+
+impl fmt::Display for CreateTableLikeProperty {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            CreateTableLikeProperty::All => "ALL",
+            CreateTableLikeProperty::Comments => "COMMENTS",
+            CreateTableLikeProperty::Compression => "COMPRESSION",
+            CreateTableLikeProperty::Constraints => "CONSTRAINTS",
+            CreateTableLikeProperty::Defaults => "DEFAULTS",
+            CreateTableLikeProperty::Generated => "GENERATED",
+            CreateTableLikeProperty::Identity => "IDENTITY",
+            CreateTableLikeProperty::Indexes => "INDEXES",
+            CreateTableLikeProperty::Statistics => "STATISTICS",
+            CreateTableLikeProperty::Storage => "STORAGE",
+        })
+    }
+}
+
+/// The partition bound of a PostgreSQL `CREATE TABLE ... PARTITION OF` statement.
+///
+/// [PostgreSQL](https://www.postgresql.org/docs/current/sql-createtable.html)
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum ForValues {
+    /// `FOR VALUES IN ( expr [, ...] )`
+    In(Vec<Expr>),
+    /// `FOR VALUES FROM ( bound [, ...] ) TO ( bound [, ...] )`
+    From {
+        from: Vec<PartitionBoundValue>,
+        to: Vec<PartitionBoundValue>,
+    },
+    /// `FOR VALUES WITH ( MODULUS n, REMAINDER r )`
+    With { modulus: u64, remainder: u64 },
+    /// `DEFAULT`
+    Default,
+}
+
+impl fmt::Display for ForValues {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ForValues::In(values) => {
+                write!(f, "FOR VALUES IN ({})", display_comma_separated(values))
+            }
+            ForValues::From { from, to } => write!(
+                f,
+                "FOR VALUES FROM ({}) TO ({})",
+                display_comma_separated(from),
+                display_comma_separated(to)
+            ),
+            ForValues::With { modulus, remainder } => write!(
+                f,
+                "FOR VALUES WITH (MODULUS {modulus}, REMAINDER {remainder})"
+            ),
+            ForValues::Default => write!(f, "DEFAULT"),
+        }
+    }
+}
+
+/// A single bound value of a range partition.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum PartitionBoundValue {
+    Expr(Expr),
+    MinValue,
+    MaxValue,
+}
+
+impl fmt::Display for PartitionBoundValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PartitionBoundValue::Expr(expr) => write!(f, "{expr}"),
+            PartitionBoundValue::MinValue => write!(f, "MINVALUE"),
+            PartitionBoundValue::MaxValue => write!(f, "MAXVALUE"),
+        }
+    }
+}
+
+/// The modifier of a PostgreSQL `ALTER TABLE ... DETACH PARTITION` operation.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum DetachPartitionModifier {
+    Concurrently,
+    Finalize,
+}
+
+impl fmt::Display for DetachPartitionModifier {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DetachPartitionModifier::Concurrently => write!(f, "CONCURRENTLY"),
+            DetachPartitionModifier::Finalize => write!(f, "FINALIZE"),
+        }
+    }
+}
+
+/// An option of a PostgreSQL `CREATE DATABASE` statement.
+///
+/// See <https://www.postgresql.org/docs/current/sql-createdatabase.html>
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum CreateDatabaseOption {
+    /// `OWNER = user_name`
+    Owner(Ident),
+    /// `TEMPLATE = template`
+    Template(Ident),
+    /// `ENCODING = encoding`
+    Encoding(Expr),
+    /// `STRATEGY = strategy`
+    Strategy(Ident),
+    /// `LOCALE = locale`
+    Locale(Expr),
+    /// `LC_COLLATE = lc_collate`
+    LcCollate(Expr),
+    /// `LC_CTYPE = lc_ctype`
+    LcCtype(Expr),
+    /// `TABLESPACE = tablespace_name`
+    Tablespace(Ident),
+    /// `ALLOW_CONNECTIONS = allowconn`
+    AllowConnections(Expr),
+    /// `CONNECTION LIMIT = connlimit`
+    ConnectionLimit(Expr),
+    /// `IS_TEMPLATE = istemplate`
+    IsTemplate(Expr),
+}
+
+impl fmt::Display for CreateDatabaseOption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CreateDatabaseOption::Owner(owner) => write!(f, "OWNER = {owner}"),
+            CreateDatabaseOption::Template(template) => write!(f, "TEMPLATE = {template}"),
+            CreateDatabaseOption::Encoding(encoding) => write!(f, "ENCODING = {encoding}"),
+            CreateDatabaseOption::Strategy(strategy) => write!(f, "STRATEGY = {strategy}"),
+            CreateDatabaseOption::Locale(locale) => write!(f, "LOCALE = {locale}"),
+            CreateDatabaseOption::LcCollate(collate) => write!(f, "LC_COLLATE = {collate}"),
+            CreateDatabaseOption::LcCtype(ctype) => write!(f, "LC_CTYPE = {ctype}"),
+            CreateDatabaseOption::Tablespace(tablespace) => {
+                write!(f, "TABLESPACE = {tablespace}")
+            }
+            CreateDatabaseOption::AllowConnections(allow) => {
+                write!(f, "ALLOW_CONNECTIONS = {allow}")
+            }
+            CreateDatabaseOption::ConnectionLimit(limit) => write!(f, "CONNECTION LIMIT = {limit}"),
+            CreateDatabaseOption::IsTemplate(is_template) => {
+                write!(f, "IS_TEMPLATE = {is_template}")
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
@@ -2343,6 +2686,64 @@ impl fmt::Display for CreateDomain {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+/// ```sql
+/// CREATE [ OR REPLACE ] AGGREGATE name ( [ argmode ] [ argname ] arg_data_type [ , ... ] ) (
+///     SFUNC = sfunc,
+///     STYPE = state_data_type
+///     [ , option [ = value ] ] ...
+/// )
+/// ```
+/// See [PostgreSQL](https://www.postgresql.org/docs/current/sql-createaggregate.html)
+pub struct CreateAggregate {
+    /// True if this is a `CREATE OR REPLACE AGGREGATE` statement.
+    pub or_replace: bool,
+    /// The name of the aggregate to be created.
+    pub name: ObjectName,
+    /// The input arguments of the aggregate.
+    pub args: Vec<OperateFunctionArg>,
+    /// The parenthesized list of aggregate options.
+    pub options: Vec<CreateAggregateOption>,
+}
+
+impl fmt::Display for CreateAggregate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "CREATE {or_replace}AGGREGATE {name} ({args}) ({options})",
+            or_replace = if self.or_replace { "OR REPLACE " } else { "" },
+            name = self.name,
+            args = display_comma_separated(&self.args),
+            options = display_comma_separated(&self.options),
+        )
+    }
+}
+
+/// An option of a `CREATE AGGREGATE` statement.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum CreateAggregateOption {
+    /// An option whose value is a data type, e.g. `STYPE = float8[]`
+    DataType { key: Ident, data_type: DataType },
+    /// An option with a value, e.g. `SFUNC = float8_accum` or `INITCOND = '{0,0,0}'`
+    KeyValue { key: Ident, value: Expr },
+    /// An option without a value, e.g. `HYPOTHETICAL`
+    Flag(Ident),
+}
+
+impl fmt::Display for CreateAggregateOption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CreateAggregateOption::DataType { key, data_type } => write!(f, "{key} = {data_type}"),
+            CreateAggregateOption::KeyValue { key, value } => write!(f, "{key} = {value}"),
+            CreateAggregateOption::Flag(key) => write!(f, "{key}"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
@@ -35,9 +35,10 @@ pub use super::ddl::{ColumnDef, TableConstraint};
 
 use super::{
     display_comma_separated, display_separated, query::InputFormatClause, Assignment, ClusteredBy,
-    CommentDef, CreateTableOptions, Expr, FileFormat, FromTable, HiveDistributionStyle, HiveFormat,
-    HiveIOFormat, HiveRowFormat, Ident, IndexType, InsertAliases, MysqlInsertPriority, ObjectName,
-    OnCommit, OnInsert, OneOrManyWithParens, OrderByExpr, Query, RowAccessPolicy, SelectItem,
+    CommentDef, CreateTableLikeKind, CreateTableOptions, Expr, FileFormat, ForValues, FromTable,
+    HiveDistributionStyle, HiveFormat, HiveIOFormat, HiveRowFormat, Ident, IndexType,
+    InsertAliases, MysqlInsertPriority, ObjectName, OnCommit, OnInsert, OneOrManyWithParens,
+    OrderByExpr, PartitionStrategy, PeriodForSystemTime, Query, RowAccessPolicy, SelectItem,
     Setting, SqliteOnConflict, StorageSerializationPolicy, TableObject, TableWithJoins, Tag,
     WrappedCollection,
 };
@@ -146,6 +147,17 @@ pub struct CreateTable {
     /// Optional schema
     pub columns: Vec<ColumnDef>,
     pub constraints: Vec<TableConstraint>,
+    /// MariaDB/MsSql: `PERIOD FOR SYSTEM_TIME (start, end)` in the column list
+    /// <https://mariadb.com/kb/en/system-versioned-tables/>
+    pub period_for_system_time: Option<PeriodForSystemTime>,
+    /// MariaDB: `WITH SYSTEM VERSIONING` after the column list
+    /// <https://mariadb.com/kb/en/system-versioned-tables/>
+    ///
+    /// This is not set for the MsSql `WITH (SYSTEM_VERSIONING = ON)` table option,
+    /// which may carry sub-options such as `HISTORY_TABLE` and so is kept as is
+    /// in [`CreateTable::table_options`].
+    /// <https://learn.microsoft.com/en-us/sql/relational-databases/tables/creating-a-system-versioned-temporal-table>
+    pub with_system_versioning: bool,
     pub hive_distribution: HiveDistributionStyle,
     pub hive_formats: Option<HiveFormat>,
     pub table_options: CreateTableOptions,
@@ -153,7 +165,7 @@ pub struct CreateTable {
     pub location: Option<String>,
     pub query: Option<Box<Query>>,
     pub without_rowid: bool,
-    pub like: Option<ObjectName>,
+    pub like: Option<CreateTableLikeKind>,
     pub clone: Option<ObjectName>,
     // For Hive dialect, the table comment is after the column definitions without `=`,
     // so the `comment` field is optional and different than the comment field in the general options list.
@@ -173,6 +185,15 @@ pub struct CreateTable {
     /// BigQuery: A partition expression for the table.
     /// <https://cloud.google.com/bigquery/docs/reference/standard-sql/data-definition-language#partition_expression>
     pub partition_by: Option<Box<Expr>>,
+    /// PostgreSQL: The partitioning strategy of a partitioned table.
+    /// <https://www.postgresql.org/docs/current/ddl-partitioning.html>
+    pub partition_strategy: Option<PartitionStrategy>,
+    /// PostgreSQL: The parent table of a `PARTITION OF` table.
+    /// <https://www.postgresql.org/docs/current/sql-createtable.html>
+    pub partition_of: Option<ObjectName>,
+    /// PostgreSQL: The partition bound of a `PARTITION OF` table.
+    /// <https://www.postgresql.org/docs/current/sql-createtable.html>
+    pub for_values: Option<ForValues>,
     /// BigQuery: Table clustering column list.
     /// <https://cloud.google.com/bigquery/docs/reference/standard-sql/data-definition-language#table_option_list>
     /// Snowflake: Table clustering list which contains base column, expressions on base columns.
@@ -268,6 +289,9 @@ impl Display for CreateTable {
         if let Some(on_cluster) = &self.on_cluster {
             write!(f, " ON CLUSTER {on_cluster}")?;
         }
+        if let Some(partition_of) = &self.partition_of {
+            write!(f, " PARTITION OF {partition_of}")?;
+        }
         if !self.columns.is_empty() || !self.constraints.is_empty() {
             f.write_str(" (")?;
             NewLine.fmt(f)?;
@@ -277,13 +301,32 @@ impl Display for CreateTable {
                 SpaceOrNewline.fmt(f)?;
             }
             Indent(DisplayCommaSeparated(&self.constraints)).fmt(f)?;
+            if let Some(period) = &self.period_for_system_time {
+                f.write_str(",")?;
+                SpaceOrNewline.fmt(f)?;
+                Indent(period).fmt(f)?;
+            }
             NewLine.fmt(f)?;
             f.write_str(")")?;
-        } else if self.query.is_none() && self.like.is_none() && self.clone.is_none() {
+        } else if let Some(CreateTableLikeKind::Parenthesized(like)) = &self.like {
+            write!(f, " ({like})")?;
+        } else if self.query.is_none()
+            && self.like.is_none()
+            && self.clone.is_none()
+            && self.partition_of.is_none()
+        {
             // PostgreSQL allows `CREATE TABLE t ();`, but requires empty parens
             f.write_str(" ()")?;
         }
 
+        if let Some(for_values) = &self.for_values {
+            write!(f, " {for_values}")?;
+        }
+
+        if self.with_system_versioning {
+            write!(f, " WITH SYSTEM VERSIONING")?;
+        }
+
         // Hive table comment should be after column definitions, please refer to:
         // [Hive](https://cwiki.apache.org/confluence/display/Hive/LanguageManual+DDL#LanguageManualDDL-CreateTable)
         if let Some(comment) = &self.comment {
@@ -296,8 +339,8 @@ impl Display for CreateTable {
         }
 
         // Only for Hive
-        if let Some(l) = &self.like {
-            write!(f, " LIKE {l}")?;
+        if let Some(CreateTableLikeKind::Plain(like)) = &self.like {
+            write!(f, " {like}")?;
         }
 
         if let Some(c) = &self.clone {
@@ -399,6 +442,9 @@ impl Display for CreateTable {
         if let Some(partition_by) = self.partition_by.as_ref() {
             write!(f, " PARTITION BY {partition_by}")?;
         }
+        if let Some(partition_strategy) = &self.partition_strategy {
+            write!(f, " PARTITION BY {partition_strategy}")?;
+        }
         if let Some(cluster_by) = self.cluster_by.as_ref() {
             write!(f, " CLUSTER BY {cluster_by}")?;
         }
@@ -641,6 +687,116 @@ impl Display for Insert {
     }
 }
 
+/// Snowflake multi-table `INSERT ALL` / `INSERT FIRST` statement.
+///
+/// ```sql
+/// INSERT [ OVERWRITE ] { ALL | FIRST }
+///   { WHEN <condition> THEN intoClause [ ... ] } [ ... ]
+///   [ ELSE intoClause ]
+/// <subquery>
+/// ```
+///
+/// See <https://docs.snowflake.com/en/sql-reference/sql/insert-multi-table>
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct InsertMultiple {
+    /// `INSERT OVERWRITE`
+    pub overwrite: bool,
+    /// `ALL` or `FIRST`
+    pub kind: InsertMultipleKind,
+    /// Unconditional `INTO` clauses, only valid with `ALL`
+    pub into_clauses: Vec<InsertMultipleIntoClause>,
+    /// Conditional `WHEN <condition> THEN INTO ...` clauses
+    pub when_clauses: Vec<InsertMultipleWhenClause>,
+    /// `ELSE INTO ...` clauses
+    pub else_clauses: Option<Vec<InsertMultipleIntoClause>>,
+    /// The query providing the rows to insert
+    pub source: Box<Query>,
+}
+
+impl Display for InsertMultiple {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "INSERT")?;
+        if self.overwrite {
+            write!(f, " OVERWRITE")?;
+        }
+        write!(f, " {}", self.kind)?;
+        if !self.into_clauses.is_empty() {
+            write!(f, " {}", display_separated(&self.into_clauses, " "))?;
+        }
+        if !self.when_clauses.is_empty() {
+            write!(f, " {}", display_separated(&self.when_clauses, " "))?;
+        }
+        if let Some(else_clauses) = &self.else_clauses {
+            write!(f, " ELSE {}", display_separated(else_clauses, " "))?;
+        }
+        write!(f, " {}", self.source)
+    }
+}
+
+/// Whether a multi-table insert evaluates every `WHEN` clause or only the first match.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum InsertMultipleKind {
+    All,
+    First,
+}
+
+impl Display for InsertMultipleKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InsertMultipleKind::All => write!(f, "ALL"),
+            InsertMultipleKind::First => write!(f, "FIRST"),
+        }
+    }
+}
+
+/// A single `INTO <table> [ (<columns>) ] [ VALUES (<exprs>) ]` target of a multi-table insert.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct InsertMultipleIntoClause {
+    #[cfg_attr(feature = "visitor", visit(with = "visit_relation"))]
+    pub table_name: ObjectName,
+    pub columns: Vec<Ident>,
+    pub values: Option<Vec<Expr>>,
+}
+
+impl Display for InsertMultipleIntoClause {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "INTO {}", self.table_name)?;
+        if !self.columns.is_empty() {
+            write!(f, " ({})", display_comma_separated(&self.columns))?;
+        }
+        if let Some(values) = &self.values {
+            write!(f, " VALUES ({})", display_comma_separated(values))?;
+        }
+        Ok(())
+    }
+}
+
+/// A `WHEN <condition> THEN INTO ...` clause of a multi-table insert.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct InsertMultipleWhenClause {
+    pub condition: Expr,
+    pub into_clauses: Vec<InsertMultipleIntoClause>,
+}
+
+impl Display for InsertMultipleWhenClause {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "WHEN {} THEN {}",
+            self.condition,
+            display_separated(&self.into_clauses, " ")
+        )
+    }
+}
+
 /// DELETE statement.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -26,10 +26,10 @@ use sqlparser_derive::{Visit, VisitMut};
 
 use super::super::dml::CreateTable;
 use crate::ast::{
-    ClusteredBy, ColumnDef, CommentDef, CreateTableOptions, Expr, FileFormat,
-    HiveDistributionStyle, HiveFormat, Ident, ObjectName, OnCommit, OneOrManyWithParens, Query,
-    RowAccessPolicy, Statement, StorageSerializationPolicy, TableConstraint, Tag,
-    WrappedCollection,
+    ClusteredBy, ColumnDef, CommentDef, CreateTableLikeKind, CreateTableOptions, Expr, FileFormat,
+    ForValues, HiveDistributionStyle, HiveFormat, Ident, ObjectName, OnCommit, OneOrManyWithParens,
+    PartitionStrategy, PeriodForSystemTime, Query, RowAccessPolicy, Statement,
+    StorageSerializationPolicy, TableConstraint, Tag, WrappedCollection,
 };
 
 use crate::parser::ParserError;
@@ -76,13 +76,15 @@ pub struct CreateTableBuilder {
     pub name: ObjectName,
     pub columns: Vec<ColumnDef>,
     pub constraints: Vec<TableConstraint>,
+    pub period_for_system_time: Option<PeriodForSystemTime>,
+    pub with_system_versioning: bool,
     pub hive_distribution: HiveDistributionStyle,
     pub hive_formats: Option<HiveFormat>,
     pub file_format: Option<FileFormat>,
     pub location: Option<String>,
     pub query: Option<Box<Query>>,
     pub without_rowid: bool,
-    pub like: Option<ObjectName>,
+    pub like: Option<CreateTableLikeKind>,
     pub clone: Option<ObjectName>,
     pub comment: Option<CommentDef>,
     pub on_commit: Option<OnCommit>,
@@ -90,6 +92,9 @@ pub struct CreateTableBuilder {
     pub primary_key: Option<Box<Expr>>,
     pub order_by: Option<OneOrManyWithParens<Expr>>,
     pub partition_by: Option<Box<Expr>>,
+    pub partition_strategy: Option<PartitionStrategy>,
+    pub partition_of: Option<ObjectName>,
+    pub for_values: Option<ForValues>,
     pub cluster_by: Option<WrappedCollection<Vec<Expr>>>,
     pub clustered_by: Option<ClusteredBy>,
     pub inherits: Option<Vec<ObjectName>>,
@@ -125,6 +130,8 @@ impl CreateTableBuilder {
             name,
             columns: vec![],
             constraints: vec![],
+            period_for_system_time: None,
+            with_system_versioning: false,
             hive_distribution: HiveDistributionStyle::NONE,
             hive_formats: None,
             file_format: None,
@@ -139,6 +146,9 @@ impl CreateTableBuilder {
             primary_key: None,
             order_by: None,
             partition_by: None,
+            partition_strategy: None,
+            partition_of: None,
+            for_values: None,
             cluster_by: None,
             clustered_by: None,
             inherits: None,
@@ -210,6 +220,19 @@ impl CreateTableBuilder {
         self
     }
 
+    pub fn period_for_system_time(
+        mut self,
+        period_for_system_time: Option<PeriodForSystemTime>,
+    ) -> Self {
+        self.period_for_system_time = period_for_system_time;
+        self
+    }
+
+    pub fn with_system_versioning(mut self, with_system_versioning: bool) -> Self {
+        self.with_system_versioning = with_system_versioning;
+        self
+    }
+
     pub fn hive_distribution(mut self, hive_distribution: HiveDistributionStyle) -> Self {
         self.hive_distribution = hive_distribution;
         self
@@ -238,7 +261,7 @@ impl CreateTableBuilder {
         self
     }
 
-    pub fn like(mut self, like: Option<ObjectName>) -> Self {
+    pub fn like(mut self, like: Option<CreateTableLikeKind>) -> Self {
         self.like = like;
         self
     }
@@ -279,6 +302,21 @@ impl CreateTableBuilder {
         self
     }
 
+    pub fn partition_strategy(mut self, partition_strategy: Option<PartitionStrategy>) -> Self {
+        self.partition_strategy = partition_strategy;
+        self
+    }
+
+    pub fn partition_of(mut self, partition_of: Option<ObjectName>) -> Self {
+        self.partition_of = partition_of;
+        self
+    }
+
+    pub fn for_values(mut self, for_values: Option<ForValues>) -> Self {
+        self.for_values = for_values;
+        self
+    }
+
     pub fn cluster_by(mut self, cluster_by: Option<WrappedCollection<Vec<Expr>>>) -> Self {
         self.cluster_by = cluster_by;
         self
@@ -416,6 +454,8 @@ impl CreateTableBuilder {
             name: self.name,
             columns: self.columns,
             constraints: self.constraints,
+            period_for_system_time: self.period_for_system_time,
+            with_system_versioning: self.with_system_versioning,
             hive_distribution: self.hive_distribution,
             hive_formats: self.hive_formats,
             file_format: self.file_format,
@@ -430,6 +470,9 @@ impl CreateTableBuilder {
             primary_key: self.primary_key,
             order_by: self.order_by,
             partition_by: self.partition_by,
+            partition_strategy: self.partition_strategy,
+            partition_of: self.partition_of,
+            for_values: self.for_values,
             cluster_by: self.cluster_by,
             clustered_by: self.clustered_by,
             inherits: self.inherits,
@@ -472,6 +515,8 @@ impl TryFrom<Statement> for CreateTableBuilder {
                 name,
                 columns,
                 constraints,
+                period_for_system_time,
+                with_system_versioning,
                 hive_distribution,
                 hive_formats,
                 file_format,
@@ -486,6 +531,9 @@ impl TryFrom<Statement> for CreateTableBuilder {
                 primary_key,
                 order_by,
                 partition_by,
+                partition_strategy,
+                partition_of,
+                for_values,
                 cluster_by,
                 clustered_by,
                 inherits,
@@ -515,6 +563,8 @@ impl TryFrom<Statement> for CreateTableBuilder {
                 name,
                 columns,
                 constraints,
+                period_for_system_time,
+                with_system_versioning,
                 hive_distribution,
                 hive_formats,
                 file_format,
@@ -529,6 +579,9 @@ impl TryFrom<Statement> for CreateTableBuilder {
                 primary_key,
                 order_by,
                 partition_by,
+                partition_strategy,
+                partition_of,
+                for_values,
                 cluster_by,
                 clustered_by,
                 inherits,
@@ -562,6 +615,7 @@ impl TryFrom<Statement> for CreateTableBuilder {
 #[derive(Default)]
 pub(crate) struct CreateTableConfiguration {
     pub partition_by: Option<Box<Expr>>,
+    pub partition_strategy: Option<PartitionStrategy>,
     pub cluster_by: Option<WrappedCollection<Vec<Expr>>>,
     pub inherits: Option<Vec<ObjectName>>,
     pub table_options: CreateTableOptions,
@@ -62,18 +62,24 @@ pub use self::ddl::{
     AlterTableAlgorithm, AlterTableLock, AlterTableOperation, AlterType, AlterTypeAddValue,
     AlterTypeAddValuePosition, AlterTypeOperation, AlterTypeRename, AlterTypeRenameValue,
     ClusteredBy, ColumnDef, ColumnOption, ColumnOptionDef, ColumnOptions, ColumnPolicy,
-    ColumnPolicyProperty, ConstraintCharacteristics, CreateConnector, CreateDomain, CreateFunction,
-    Deduplicate, DeferrableInitial, DropBehavior, GeneratedAs, GeneratedExpressionMode,
-    IdentityParameters, IdentityProperty, IdentityPropertyFormatKind, IdentityPropertyKind,
-    IdentityPropertyOrder, IndexOption, IndexType, KeyOrIndexDisplay, NullsDistinctOption, Owner,
-    Partition, ProcedureParam, ReferentialAction, ReplicaIdentity, TableConstraint,
+    ColumnPolicyProperty, ConstraintCharacteristics, CreateAggregate, CreateAggregateOption,
+    CreateConnector, CreateDatabaseOption, CreateDomain, CreateFunction, CreateTableLike,
+    CreateTableLikeKind, CreateTableLikeOption, CreateTableLikeProperty, Deduplicate,
+    DeferrableInitial, DetachPartitionModifier, DropBehavior, ForValues, GeneratedAs,
+    GeneratedExpressionMode, IdentityParameters, IdentityProperty, IdentityPropertyFormatKind,
+    IdentityPropertyKind, IdentityPropertyOrder, IndexOption, IndexType, KeyOrIndexDisplay,
+    NullsDistinctOption, Owner, Partition, PartitionBoundValue, PartitionStrategy,
+    PeriodForSystemTime, ProcedureParam, ReferentialAction, ReplicaIdentity, TableConstraint,
     TagsColumnOption, UserDefinedTypeCompositeAttributeDef, UserDefinedTypeRepresentation,
     ViewColumnDef,
 };
-pub use self::dml::{CreateIndex, CreateTable, Delete, IndexColumn, Insert};
+pub use self::dml::{
+    CreateIndex, CreateTable, Delete, IndexColumn, Insert, InsertMultiple,
+    InsertMultipleIntoClause, InsertMultipleKind, InsertMultipleWhenClause,
+};
 pub use self::operator::{BinaryOperator, UnaryOperator};
 pub use self::query::{
-    AfterMatchSkip, ConnectBy, Cte, CteAsMaterialized, Distinct, EmptyMatchesMode,
+    AfterMatchSkip, ArrayJoin, ConnectBy, Cte, CteAsMaterialized, Distinct, EmptyMatchesMode,
     ExceptSelectItem, ExcludeSelectItem, ExprWithAlias, ExprWithAliasAndOrderBy, Fetch, ForClause,
     ForJson, ForXml, FormatClause, GroupByExpr, GroupByWithModifier, IdentWithAlias,
     IlikeSelectItem, InputFormatClause, Interpolate, InterpolateExpr, Join, JoinConstraint,
@@ -1777,7 +1783,10 @@ impl fmt::Display for Expr {
                 for set in sets {
                     write!(f, "{sep}")?;
                     sep = ", ";
-                    write!(f, "({})", display_comma_separated(set))?;
+                    match set.as_slice() {
+                        [expr @ (Expr::Rollup(_) | Expr::Cube(_))] => write!(f, "{expr}")?,
+                        _ => write!(f, "({})", display_comma_separated(set))?,
+                    }
                 }
                 write!(f, ")")
             }
@@ -1868,6 +1877,8 @@ impl fmt::Display for Expr {
                 }
                 if let Some(trim_char) = trim_what {
                     write!(f, "{trim_char} FROM {expr}")?;
+                } else if trim_where.is_some() {
+                    write!(f, "FROM {expr}")?;
                 } else {
                     write!(f, "{expr}")?;
                 }
@@ -2026,6 +2037,9 @@ impl fmt::Display for WindowSpec {
             } else {
                 write!(f, "{} {}", window_frame.units, window_frame.start_bound)?;
             }
+            if let Some(exclusion) = &window_frame.exclusion {
+                write!(f, " {exclusion}")?;
+            }
         }
         Ok(())
     }
@@ -2046,7 +2060,8 @@ pub struct WindowFrame {
     /// indicates the shorthand form (e.g. `ROWS 1 PRECEDING`), which must
     /// behave the same as `end_bound = WindowFrameBound::CurrentRow`.
     pub end_bound: Option<WindowFrameBound>,
-    // TBD: EXCLUDE
+    /// The optional `EXCLUDE` clause, e.g. `EXCLUDE CURRENT ROW`.
+    pub exclusion: Option<WindowFrameExclusion>,
 }
 
 impl Default for WindowFrame {
@@ -2058,6 +2073,7 @@ impl Default for WindowFrame {
             units: WindowFrameUnits::Range,
             start_bound: WindowFrameBound::Preceding(None),
             end_bound: None,
+            exclusion: None,
         }
     }
 }
@@ -2081,6 +2097,30 @@ impl fmt::Display for WindowFrameUnits {
     }
 }
 
+/// Specifies the rows excluded from a [WindowFrame], e.g. `EXCLUDE CURRENT ROW`.
+///
+/// See [PostgreSQL](https://www.postgresql.org/docs/current/sql-expressions.html#SYNTAX-WINDOW-FUNCTIONS)
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum WindowFrameExclusion {
+    CurrentRow,
+    Group,
+    Ties,
+    NoOthers,
+}
+
+impl fmt::Display for WindowFrameExclusion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            WindowFrameExclusion::CurrentRow => "EXCLUDE CURRENT ROW",
+            WindowFrameExclusion::Group => "EXCLUDE GROUP",
+            WindowFrameExclusion::Ties => "EXCLUDE TIES",
+            WindowFrameExclusion::NoOthers => "EXCLUDE NO OTHERS",
+        })
+    }
+}
+
 /// Specifies Ignore / Respect NULL within window functions.
 /// For example
 /// `FIRST_VALUE(column2) IGNORE NULLS OVER (PARTITION BY column1)`
@@ -2170,6 +2210,26 @@ impl fmt::Display for ShowCreateObject {
     }
 }
 
+/// The keyword used in a MySQL `SHOW {INDEX | INDEXES | KEYS}` statement.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum ShowIndexKeyword {
+    Index,
+    Indexes,
+    Keys,
+}
+
+impl fmt::Display for ShowIndexKeyword {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ShowIndexKeyword::Index => f.write_str("INDEX"),
+            ShowIndexKeyword::Indexes => f.write_str("INDEXES"),
+            ShowIndexKeyword::Keys => f.write_str("KEYS"),
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
@@ -2920,6 +2980,17 @@ pub enum Set {
         role_name: Option<Ident>,
     },
     /// ```sql
+    /// SET [ SESSION | LOCAL ] SESSION AUTHORIZATION { user_name | DEFAULT }
+    /// ```
+    ///
+    /// [PostgreSQL](https://www.postgresql.org/docs/current/sql-set-session-authorization.html)
+    SetSessionAuthorization {
+        /// Optional identifier to inform if the setting applies to the session (`SESSION`) or transaction (`LOCAL`).
+        context_modifier: Option<ContextModifier>,
+        /// User name. If DEFAULT is specified, then the session user is reset.
+        user_name: Option<Ident>,
+    },
+    /// ```sql
     /// SET TIME ZONE <value>
     /// ```
     ///
@@ -2973,6 +3044,20 @@ impl Display for Set {
                     modifier = context_modifier.map(|m| format!("{m}")).unwrap_or_default()
                 )
             }
+            Self::SetSessionAuthorization {
+                context_modifier,
+                user_name,
+            } => {
+                write!(
+                    f,
+                    "SET {modifier}SESSION AUTHORIZATION ",
+                    modifier = context_modifier.map(|m| format!("{m}")).unwrap_or_default()
+                )?;
+                match user_name {
+                    Some(user_name) => write!(f, "{user_name}"),
+                    None => write!(f, "DEFAULT"),
+                }
+            }
             Self::SetSessionParam(kind) => write!(f, "SET {kind}"),
             Self::SetTransaction {
                 modes,
@@ -3044,6 +3129,38 @@ impl From<Set> for Statement {
     }
 }
 
+/// A `RESET` statement, restoring a run-time parameter to its default value.
+///
+/// ```sql
+/// RESET { configuration_parameter | ALL | ROLE | SESSION AUTHORIZATION }
+/// ```
+///
+/// [PostgreSQL](https://www.postgresql.org/docs/current/sql-reset.html)
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum Reset {
+    /// `RESET ALL`
+    All,
+    /// `RESET ROLE`
+    Role,
+    /// `RESET SESSION AUTHORIZATION`
+    SessionAuthorization,
+    /// `RESET configuration_parameter`
+    ConfigurationParameter(ObjectName),
+}
+
+impl fmt::Display for Reset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Reset::All => write!(f, "RESET ALL"),
+            Reset::Role => write!(f, "RESET ROLE"),
+            Reset::SessionAuthorization => write!(f, "RESET SESSION AUTHORIZATION"),
+            Reset::ConfigurationParameter(name) => write!(f, "RESET {name}"),
+        }
+    }
+}
+
 /// A representation of a `WHEN` arm with all the identifiers catched and the statements to execute
 /// for the arm.
 ///
@@ -3101,6 +3218,10 @@ pub enum Statement {
     },
     Set(Set),
     /// ```sql
+    /// RESET { configuration_parameter | ALL | ROLE | SESSION AUTHORIZATION }
+    /// ```
+    Reset(Reset),
+    /// ```sql
     /// TRUNCATE
     /// ```
     /// Truncate (Hive)
@@ -3140,6 +3261,12 @@ pub enum Statement {
     /// ```
     Insert(Insert),
     /// ```sql
+    /// INSERT [ OVERWRITE ] { ALL | FIRST } ...
+    /// ```
+    /// Snowflake multi-table insert.
+    /// See <https://docs.snowflake.com/en/sql-reference/sql/insert-multi-table>
+    InsertMultiple(InsertMultiple),
+    /// ```sql
     /// INSTALL
     /// ```
     Install {
@@ -3281,6 +3408,9 @@ pub enum Statement {
         to: Option<ObjectName>,
         /// MySQL: Optional parameters for the view algorithm, definer, and security context
         params: Option<CreateViewParams>,
+        /// PostgreSQL/MySQL: `WITH [ CASCADED | LOCAL ] CHECK OPTION`
+        /// <https://www.postgresql.org/docs/current/sql-createview.html>
+        with_check_option: Option<CheckOption>,
     },
     /// ```sql
     /// CREATE TABLE
@@ -3457,6 +3587,16 @@ pub enum Statement {
         /// true if the syntax is 'ATTACH DATABASE', false if it's just 'ATTACH'
         database: bool,
     },
+    /// ```sql
+    /// DETACH DATABASE alias
+    /// ```
+    /// (SQLite-specific)
+    DetachDatabase {
+        /// The name of the attached database to detach
+        schema_name: Ident,
+        /// true if the syntax is 'DETACH DATABASE', false if it's just 'DETACH'
+        database: bool,
+    },
     /// (DuckDB-specific)
     /// ```sql
     /// ATTACH 'sqlite_file.db' AS sqlite_db (READ_ONLY, TYPE SQLITE);
@@ -3498,6 +3638,9 @@ pub enum Statement {
         /// Whether `RESTRICT` was specified. This will be `false` when
         /// `CASCADE` or no drop behavior at all was specified.
         restrict: bool,
+        /// Whether the Oracle-style `CASCADE CONSTRAINTS` was specified.
+        /// When `true`, `cascade` is also `true`.
+        cascade_constraints: bool,
         /// Hive allows you specify whether the table's stored data will be
         /// deleted along with the dropped table
         purge: bool,
@@ -3689,6 +3832,17 @@ pub enum Statement {
         show_options: ShowStatementOptions,
     },
     /// ```sql
+    /// SHOW [EXTENDED] {INDEX | INDEXES | KEYS} {FROM | IN} tbl_name
+    /// ```
+    ///
+    /// Note: this is a MySQL-specific statement.
+    /// <https://dev.mysql.com/doc/refman/8.4/en/show-index.html>
+    ShowIndex {
+        extended: bool,
+        keyword: ShowIndexKeyword,
+        show_options: ShowStatementOptions,
+    },
+    /// ```sql
     /// SHOW DATABASES
     /// ```
     ShowDatabases {
@@ -3871,6 +4025,14 @@ pub enum Statement {
         ///
         /// [Snowflake](https://docs.snowflake.com/en/sql-reference/sql/create-clone#databases-schemas)
         clone: Option<ObjectName>,
+        /// Options such as `OWNER`, `ENCODING` or `TEMPLATE`
+        ///
+        /// ```sql
+        /// CREATE DATABASE mydb WITH OWNER = admin ENCODING = 'UTF8'
+        /// ```
+        ///
+        /// [PostgreSQL](https://www.postgresql.org/docs/current/sql-createdatabase.html)
+        options: Vec<CreateDatabaseOption>,
     },
     /// ```sql
     /// CREATE FUNCTION
@@ -4032,6 +4194,8 @@ pub enum Statement {
         objects: Option<GrantObjects>,
         grantees: Vec<Grantee>,
         with_grant_option: bool,
+        /// `WITH ADMIN OPTION` when granting role membership (PostgreSQL)
+        with_admin_option: bool,
         as_grantor: Option<Ident>,
         granted_by: Option<Ident>,
         current_grants: Option<CurrentGrantsKind>,
@@ -4180,6 +4344,10 @@ pub enum Statement {
         clauses: Vec<MergeClause>,
         // Specifies the output to save changes in MSSQL
         output: Option<OutputClause>,
+        /// `RETURNING` clause
+        ///
+        /// [PostgreSQL](https://www.postgresql.org/docs/current/sql-merge.html)
+        returning: Option<Vec<SelectItem>>,
     },
     /// ```sql
     /// CACHE [ FLAG ] TABLE <table_name> [ OPTIONS('K1' = 'V1', 'K2' = V2) ] [ AS ] [ <query> ]
@@ -4224,6 +4392,9 @@ pub enum Statement {
     },
     /// A `CREATE DOMAIN` statement.
     CreateDomain(CreateDomain),
+    /// A `CREATE AGGREGATE` statement.
+    /// See [PostgreSQL](https://www.postgresql.org/docs/current/sql-createaggregate.html)
+    CreateAggregate(CreateAggregate),
     /// ```sql
     /// CREATE TYPE <name>
     /// ```
@@ -4360,6 +4531,12 @@ pub enum Statement {
     /// ```
     /// [Snowflake](https://docs.snowflake.com/en/sql-reference/sql/create-user)
     CreateUser(CreateUser),
+    /// ```sql
+    /// ALTER USER [IF EXISTS] <user> <operation>
+    /// ```
+    /// [Snowflake](https://docs.snowflake.com/en/sql-reference/sql/alter-user)
+    /// [PostgreSQL](https://www.postgresql.org/docs/current/sql-alteruser.html)
+    AlterUser(AlterUser),
 }
 
 /// ```sql
@@ -4637,6 +4814,13 @@ impl fmt::Display for Statement {
                 let keyword = if *database { "DATABASE " } else { "" };
                 write!(f, "ATTACH {keyword}{database_file_name} AS {schema_name}")
             }
+            Statement::DetachDatabase {
+                schema_name,
+                database,
+            } => {
+                let keyword = if *database { "DATABASE " } else { "" };
+                write!(f, "DETACH {keyword}{schema_name}")
+            }
             Statement::AttachDuckDBDatabase {
                 if_not_exists,
                 database,
@@ -4710,6 +4894,7 @@ impl fmt::Display for Statement {
                 Ok(())
             }
             Statement::Insert(insert) => insert.fmt(f),
+            Statement::InsertMultiple(insert) => insert.fmt(f),
             Statement::Install {
                 extension_name: name,
             } => write!(f, "INSTALL {name}"),
@@ -4819,6 +5004,7 @@ impl fmt::Display for Statement {
                 location,
                 managed_location,
                 clone,
+                options,
             } => {
                 write!(f, "CREATE DATABASE")?;
                 if *if_not_exists {
@@ -4834,10 +5020,14 @@ impl fmt::Display for Statement {
                 if let Some(clone) = clone {
                     write!(f, " CLONE {clone}")?;
                 }
+                if !options.is_empty() {
+                    write!(f, " WITH {}", display_separated(options, " "))?;
+                }
                 Ok(())
             }
             Statement::CreateFunction(create_function) => create_function.fmt(f),
             Statement::CreateDomain(create_domain) => create_domain.fmt(f),
+            Statement::CreateAggregate(create_aggregate) => create_aggregate.fmt(f),
             Statement::CreateTrigger {
                 or_alter,
                 or_replace,
@@ -4987,6 +5177,7 @@ impl fmt::Display for Statement {
                 temporary,
                 to,
                 params,
+                with_check_option,
             } => {
                 write!(
                     f,
@@ -5034,6 +5225,9 @@ impl fmt::Display for Statement {
                 if *with_no_schema_binding {
                     write!(f, " WITH NO SCHEMA BINDING")?;
                 }
+                if let Some(check_option) = with_check_option {
+                    write!(f, " {check_option}")?;
+                }
                 Ok(())
             }
             Statement::CreateTable(create_table) => create_table.fmt(f),
@@ -5419,6 +5613,7 @@ impl fmt::Display for Statement {
                 names,
                 cascade,
                 restrict,
+                cascade_constraints,
                 purge,
                 temporary,
                 table,
@@ -5430,7 +5625,11 @@ impl fmt::Display for Statement {
                     object_type,
                     if *if_exists { " IF EXISTS" } else { "" },
                     display_comma_separated(names),
-                    if *cascade { " CASCADE" } else { "" },
+                    match (*cascade, *cascade_constraints) {
+                        (true, true) => " CASCADE CONSTRAINTS",
+                        (true, false) => " CASCADE",
+                        _ => "",
+                    },
                     if *restrict { " RESTRICT" } else { "" },
                     if *purge { " PURGE" } else { "" },
                 )?;
@@ -5535,6 +5734,7 @@ impl fmt::Display for Statement {
                 Ok(())
             }
             Self::Set(set) => write!(f, "{set}"),
+            Self::Reset(reset) => write!(f, "{reset}"),
             Statement::ShowVariable { variable } => {
                 write!(f, "SHOW")?;
                 if !variable.is_empty() {
@@ -5595,6 +5795,18 @@ impl fmt::Display for Statement {
                 )?;
                 Ok(())
             }
+            Statement::ShowIndex {
+                extended,
+                keyword,
+                show_options,
+            } => {
+                write!(
+                    f,
+                    "SHOW {extended}{keyword}{show_options}",
+                    extended = if *extended { "EXTENDED " } else { "" },
+                )?;
+                Ok(())
+            }
             Statement::ShowDatabases {
                 terse,
                 history,
@@ -5793,6 +6005,7 @@ impl fmt::Display for Statement {
                 objects,
                 grantees,
                 with_grant_option,
+                with_admin_option,
                 as_grantor,
                 granted_by,
                 current_grants,
@@ -5805,6 +6018,9 @@ impl fmt::Display for Statement {
                 if *with_grant_option {
                     write!(f, " WITH GRANT OPTION")?;
                 }
+                if *with_admin_option {
+                    write!(f, " WITH ADMIN OPTION")?;
+                }
                 if let Some(current_grants) = current_grants {
                     write!(f, " {current_grants}")?;
                 }
@@ -5922,6 +6138,7 @@ impl fmt::Display for Statement {
                 on,
                 clauses,
                 output,
+                returning,
             } => {
                 write!(
                     f,
@@ -5933,6 +6150,9 @@ impl fmt::Display for Statement {
                 if let Some(output) = output {
                     write!(f, " {output}")?;
                 }
+                if let Some(returning) = returning {
+                    write!(f, " RETURNING {}", display_comma_separated(returning))?;
+                }
                 Ok(())
             }
             Statement::Cache {
@@ -6199,6 +6419,7 @@ impl fmt::Display for Statement {
             Statement::List(command) => write!(f, "LIST {command}"),
             Statement::Remove(command) => write!(f, "REMOVE {command}"),
             Statement::CreateUser(s) => write!(f, "{s}"),
+            Statement::AlterUser(s) => write!(f, "{s}"),
         }
     }
 }
@@ -6484,6 +6705,8 @@ pub enum Privileges {
     },
     /// Specific privileges (e.g. `SELECT`, `INSERT`)
     Actions(Vec<Action>),
+    /// Membership in one or more roles, e.g. PostgreSQL `GRANT role1, role2 TO user`
+    Roles(Vec<ObjectName>),
 }
 
 impl fmt::Display for Privileges {
@@ -6505,6 +6728,9 @@ impl fmt::Display for Privileges {
             Privileges::Actions(actions) => {
                 write!(f, "{}", display_comma_separated(actions))
             }
+            Privileges::Roles(roles) => {
+                write!(f, "{}", display_comma_separated(roles))
+            }
         }
     }
 }
@@ -7459,6 +7685,17 @@ pub struct Function {
     pub args: FunctionArguments,
     /// e.g. `x > 5` in `COUNT(x) FILTER (WHERE x > 5)`
     pub filter: Option<Box<Expr>>,
+    /// `FROM FIRST` (`Some(true)`) or `FROM LAST` (`Some(false)`), which
+    /// controls the direction in which a function like `NTH_VALUE` counts.
+    ///
+    /// Example:
+    /// ```plaintext
+    /// NTH_VALUE( <expr> , <n> ) [ FROM { FIRST | LAST } ] [ { IGNORE | RESPECT } NULLS ] OVER ...
+    /// ```
+    ///
+    /// [Snowflake](https://docs.snowflake.com/en/sql-reference/functions/nth_value)
+    /// [Oracle](https://docs.oracle.com/en/database/oracle/oracle-database/23/sqlrf/NTH_VALUE.html)
+    pub from_first: Option<bool>,
     /// Indicates how `NULL`s should be handled in the calculation.
     ///
     /// Example:
@@ -7500,6 +7737,12 @@ impl fmt::Display for Function {
             write!(f, " FILTER (WHERE {filter_cond})")?;
         }
 
+        match self.from_first {
+            Some(true) => write!(f, " FROM FIRST")?,
+            Some(false) => write!(f, " FROM LAST")?,
+            None => {}
+        }
+
         if let Some(null_treatment) = &self.null_treatment {
             write!(f, " {null_treatment}")?;
         }
@@ -8374,7 +8617,7 @@ impl fmt::Display for ShowStatementFilter {
         use ShowStatementFilter::*;
         match self {
             Like(pattern) => write!(f, "LIKE '{}'", value::escape_single_quote_string(pattern)),
-            ILike(pattern) => write!(f, "ILIKE {}", value::escape_single_quote_string(pattern)),
+            ILike(pattern) => write!(f, "ILIKE '{}'", value::escape_single_quote_string(pattern)),
             Where(expr) => write!(f, "WHERE {expr}"),
             NoKeyword(pattern) => write!(f, "'{}'", value::escape_single_quote_string(pattern)),
         }
@@ -9495,6 +9738,32 @@ impl Display for CreateViewSecurity {
     }
 }
 
+/// `CREATE VIEW` check option: `WITH [ CASCADED | LOCAL ] CHECK OPTION`
+///
+/// [PostgreSQL](https://www.postgresql.org/docs/current/sql-createview.html)
+/// [MySQL](https://dev.mysql.com/doc/refman/8.4/en/view-check-option.html)
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum CheckOption {
+    /// `WITH CHECK OPTION`
+    Default,
+    /// `WITH LOCAL CHECK OPTION`
+    Local,
+    /// `WITH CASCADED CHECK OPTION`
+    Cascaded,
+}
+
+impl Display for CheckOption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CheckOption::Default => write!(f, "WITH CHECK OPTION"),
+            CheckOption::Local => write!(f, "WITH LOCAL CHECK OPTION"),
+            CheckOption::Cascaded => write!(f, "WITH CASCADED CHECK OPTION"),
+        }
+    }
+}
+
 /// [MySQL] `CREATE VIEW` additional parameters
 ///
 /// [MySQL]: https://dev.mysql.com/doc/refman/9.1/en/create-view.html
@@ -10149,6 +10418,10 @@ pub struct CreateUser {
     pub options: KeyValueOptions,
     pub with_tags: bool,
     pub tags: KeyValueOptions,
+    /// PostgreSQL role options, e.g. `WITH LOGIN PASSWORD 'secret'`
+    ///
+    /// [PostgreSQL](https://www.postgresql.org/docs/current/sql-createuser.html)
+    pub role_options: Vec<RoleOption>,
 }
 
 impl fmt::Display for CreateUser {
@@ -10162,6 +10435,9 @@ impl fmt::Display for CreateUser {
             write!(f, " IF NOT EXISTS")?;
         }
         write!(f, " {}", self.name)?;
+        if !self.role_options.is_empty() {
+            write!(f, " WITH {}", display_separated(&self.role_options, " "))?;
+        }
         if !self.options.options.is_empty() {
             write!(f, " {}", self.options)?;
         }
@@ -10175,6 +10451,84 @@ impl fmt::Display for CreateUser {
     }
 }
 
+/// Modifies a user
+///
+/// Syntax:
+/// ```sql
+/// ALTER USER [IF EXISTS] <name> <operation>
+/// ```
+///
+/// [Snowflake](https://docs.snowflake.com/en/sql-reference/sql/alter-user)
+/// [PostgreSQL](https://www.postgresql.org/docs/current/sql-alteruser.html)
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct AlterUser {
+    pub if_exists: bool,
+    pub name: Ident,
+    pub operation: AlterUserOperation,
+}
+
+impl fmt::Display for AlterUser {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ALTER USER")?;
+        if self.if_exists {
+            write!(f, " IF EXISTS")?;
+        }
+        write!(f, " {} {}", self.name, self.operation)
+    }
+}
+
+/// An [AlterUser] operation
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum AlterUserOperation {
+    /// `RENAME TO <new_name>`
+    RenameTo(Ident),
+    /// `SET <property> = <value> [ ... ]` (Snowflake)
+    Set(KeyValueOptions),
+    /// `UNSET <property> [ , ... ]` (Snowflake)
+    Unset(Vec<Ident>),
+    /// `[ WITH ] <role_option> [ ... ]` (PostgreSQL)
+    WithOptions(Vec<RoleOption>),
+    /// `SET <configuration_parameter> { TO | = } { <value> | DEFAULT }` or
+    /// `SET <configuration_parameter> FROM CURRENT` (PostgreSQL)
+    SetConfig {
+        config_name: ObjectName,
+        config_value: SetConfigValue,
+    },
+    /// `RESET { <configuration_parameter> | ALL }` (PostgreSQL)
+    Reset(ResetConfig),
+}
+
+impl fmt::Display for AlterUserOperation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AlterUserOperation::RenameTo(new_name) => write!(f, "RENAME TO {new_name}"),
+            AlterUserOperation::Set(options) => write!(f, "SET {options}"),
+            AlterUserOperation::Unset(properties) => {
+                write!(f, "UNSET {}", display_comma_separated(properties))
+            }
+            AlterUserOperation::WithOptions(options) => {
+                write!(f, "WITH {}", display_separated(options, " "))
+            }
+            AlterUserOperation::SetConfig {
+                config_name,
+                config_value,
+            } => match config_value {
+                SetConfigValue::Default => write!(f, "SET {config_name} TO DEFAULT"),
+                SetConfigValue::FromCurrent => write!(f, "SET {config_name} FROM CURRENT"),
+                SetConfigValue::Value(expr) => write!(f, "SET {config_name} TO {expr}"),
+            },
+            AlterUserOperation::Reset(config_name) => match config_name {
+                ResetConfig::ALL => write!(f, "RESET ALL"),
+                ResetConfig::ConfigName(name) => write!(f, "RESET {name}"),
+            },
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::tokenizer::Location;
@@ -332,6 +332,10 @@ pub struct Select {
     pub from: Vec<TableWithJoins>,
     /// LATERAL VIEWs
     pub lateral_views: Vec<LateralView>,
+    /// ClickHouse syntax: `ARRAY JOIN arr AS a` and `LEFT ARRAY JOIN arr AS a`
+    ///
+    /// [ClickHouse](https://clickhouse.com/docs/en/sql-reference/statements/select/array-join)
+    pub array_joins: Vec<ArrayJoin>,
     /// ClickHouse syntax: `PREWHERE a = 1 WHERE b = 2`,
     /// and it can be used together with WHERE selection.
     ///
@@ -425,6 +429,9 @@ impl fmt::Display for Select {
                 lv.fmt(f)?;
             }
         }
+        for array_join in &self.array_joins {
+            array_join.fmt(f)?;
+        }
         if let Some(ref prewhere) = self.prewhere {
             f.write_str(" PREWHERE ")?;
             prewhere.fmt(f)?;
@@ -541,6 +548,34 @@ impl fmt::Display for LateralView {
     }
 }
 
+/// A ClickHouse `[LEFT] ARRAY JOIN` clause
+///
+/// ```sql
+/// SELECT s, a FROM t LEFT ARRAY JOIN arr AS a
+/// ```
+///
+/// [ClickHouse](https://clickhouse.com/docs/en/sql-reference/statements/select/array-join)
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct ArrayJoin {
+    /// `LEFT ARRAY JOIN`
+    pub left: bool,
+    /// The arrays to join, with optional aliases
+    pub exprs: Vec<ExprWithAlias>,
+}
+
+impl fmt::Display for ArrayJoin {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            " {left}ARRAY JOIN {}",
+            display_comma_separated(&self.exprs),
+            left = if self.left { "LEFT " } else { "" }
+        )
+    }
+}
+
 /// An expression used in a named window declaration.
 ///
 /// ```sql
@@ -1038,14 +1073,17 @@ pub struct ConnectBy {
     pub condition: Expr,
     /// CONNECT BY
     pub relationships: Vec<Expr>,
+    /// `CONNECT BY NOCYCLE`
+    pub nocycle: bool,
 }
 
 impl fmt::Display for ConnectBy {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
             f,
-            "START WITH {condition} CONNECT BY {relationships}",
+            "START WITH {condition} CONNECT BY {nocycle}{relationships}",
             condition = self.condition,
+            nocycle = if self.nocycle { "NOCYCLE " } else { "" },
             relationships = display_comma_separated(&self.relationships)
         )
     }
@@ -2170,6 +2208,16 @@ pub enum TableVersion {
     /// When the table version is defined using `FOR SYSTEM_TIME AS OF`.
     /// For example: `SELECT * FROM tbl FOR SYSTEM_TIME AS OF TIMESTAMP_SUB(CURRENT_TIMESTAMP(), INTERVAL 1 HOUR)`
     ForSystemTimeAsOf(Expr),
+    /// `FOR SYSTEM_TIME BETWEEN <low> AND <high>`
+    ForSystemTimeBetween { low: Expr, high: Expr },
+    /// `FOR SYSTEM_TIME FROM <from> TO <to>`
+    ForSystemTimeFromTo { from: Expr, to: Expr },
+    /// `FOR SYSTEM_TIME CONTAINED IN (<from>, <to>)`
+    ///
+    /// See <https://learn.microsoft.com/en-us/sql/relational-databases/tables/querying-data-in-a-system-versioned-temporal-table>
+    ForSystemTimeContainedIn { from: Expr, to: Expr },
+    /// `FOR SYSTEM_TIME ALL`
+    ForSystemTimeAll,
     /// When the table version is defined using a function.
     /// For example: `SELECT * FROM tbl AT(TIMESTAMP => '2020-08-14 09:30:00')`
     Function(Expr),
@@ -2179,6 +2227,16 @@ impl Display for TableVersion {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             TableVersion::ForSystemTimeAsOf(e) => write!(f, " FOR SYSTEM_TIME AS OF {e}")?,
+            TableVersion::ForSystemTimeBetween { low, high } => {
+                write!(f, " FOR SYSTEM_TIME BETWEEN {low} AND {high}")?
+            }
+            TableVersion::ForSystemTimeFromTo { from, to } => {
+                write!(f, " FOR SYSTEM_TIME FROM {from} TO {to}")?
+            }
+            TableVersion::ForSystemTimeContainedIn { from, to } => {
+                write!(f, " FOR SYSTEM_TIME CONTAINED IN ({from}, {to})")?
+            }
+            TableVersion::ForSystemTimeAll => write!(f, " FOR SYSTEM_TIME ALL")?,
             TableVersion::Function(func) => write!(f, " {func}")?,
         }
         Ok(())
@@ -3081,6 +3139,34 @@ pub struct SelectInto {
     pub name: ObjectName,
 }
 
+impl SelectInto {
+    /// Returns `true` if the target is a T-SQL local temporary table, i.e.
+    /// its name is prefixed with a single `#` such as `#tmp`.
+    ///
+    /// [MsSql](https://learn.microsoft.com/en-us/sql/t-sql/statements/create-table-transact-sql#temporary-tables)
+    pub fn is_local_temp_table(&self) -> bool {
+        self.unqualified_name()
+            .is_some_and(|name| name.starts_with('#') && !name.starts_with("##"))
+    }
+
+    /// Returns `true` if the target is a T-SQL global temporary table, i.e.
+    /// its name is prefixed with `##` such as `##tmp`.
+    ///
+    /// [MsSql](https://learn.microsoft.com/en-us/sql/t-sql/statements/create-table-transact-sql#temporary-tables)
+    pub fn is_global_temp_table(&self) -> bool {
+        self.unqualified_name()
+            .is_some_and(|name| name.starts_with("##"))
+    }
+
+    fn unqualified_name(&self) -> Option<&str> {
+        self.name
+            .0
+            .last()
+            .and_then(ObjectNamePart::as_ident)
+            .map(|ident| ident.value.as_str())
+    }
+}
+
 impl fmt::Display for SelectInto {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let temporary = if self.temporary { " TEMPORARY" } else { "" };
@@ -18,27 +18,27 @@
 use crate::ast::{query::SelectItemQualifiedWildcardKind, ColumnOptions};
 use core::iter;
 
-use crate::tokenizer::Span;
+use crate::tokenizer::{Location, Span};
 
 use super::{
     dcl::SecondaryRoles, value::ValueWithSpan, AccessExpr, AlterColumnOperation,
-    AlterIndexOperation, AlterTableOperation, Array, Assignment, AssignmentTarget, AttachedToken,
-    BeginEndStatements, CaseStatement, CloseCursor, ClusteredIndex, ColumnDef, ColumnOption,
-    ColumnOptionDef, ConditionalStatementBlock, ConditionalStatements, ConflictTarget, ConnectBy,
-    ConstraintCharacteristics, CopySource, CreateIndex, CreateTable, CreateTableOptions, Cte,
-    Delete, DoUpdate, ExceptSelectItem, ExcludeSelectItem, Expr, ExprWithAlias, Fetch, FromTable,
-    Function, FunctionArg, FunctionArgExpr, FunctionArgumentClause, FunctionArgumentList,
-    FunctionArguments, GroupByExpr, HavingBound, IfStatement, IlikeSelectItem, IndexColumn, Insert,
-    Interpolate, InterpolateExpr, Join, JoinConstraint, JoinOperator, JsonPath, JsonPathElem,
-    LateralView, LimitClause, MatchRecognizePattern, Measure, NamedParenthesizedList,
-    NamedWindowDefinition, ObjectName, ObjectNamePart, Offset, OnConflict, OnConflictAction,
-    OnInsert, OpenStatement, OrderBy, OrderByExpr, OrderByKind, Partition, PivotValueSource,
-    ProjectionSelect, Query, RaiseStatement, RaiseStatementValue, ReferentialAction,
-    RenameSelectItem, ReplaceSelectElement, ReplaceSelectItem, Select, SelectInto, SelectItem,
-    SetExpr, SqlOption, Statement, Subscript, SymbolDefinition, TableAlias, TableAliasColumnDef,
-    TableConstraint, TableFactor, TableObject, TableOptionsClustered, TableWithJoins,
-    UpdateTableFromKind, Use, Value, Values, ViewColumnDef, WhileStatement,
-    WildcardAdditionalOptions, With, WithFill,
+    AlterIndexOperation, AlterTableOperation, Array, ArrayJoin, Assignment, AssignmentTarget,
+    AttachedToken, BeginEndStatements, CaseStatement, CloseCursor, ClusteredIndex, ColumnDef,
+    ColumnOption, ColumnOptionDef, ConditionalStatementBlock, ConditionalStatements,
+    ConflictTarget, ConnectBy, ConstraintCharacteristics, CopySource, CreateIndex, CreateTable,
+    CreateTableLikeKind, CreateTableOptions, Cte, Delete, DoUpdate, ExceptSelectItem,
+    ExcludeSelectItem, Expr, ExprWithAlias, Fetch, FromTable, Function, FunctionArg,
+    FunctionArgExpr, FunctionArgumentClause, FunctionArgumentList, FunctionArguments, GroupByExpr,
+    HavingBound, IfStatement, IlikeSelectItem, IndexColumn, Insert, Interpolate, InterpolateExpr,
+    Join, JoinConstraint, JoinOperator, JsonPath, JsonPathElem, LateralView, LimitClause,
+    MatchRecognizePattern, Measure, NamedParenthesizedList, NamedWindowDefinition, ObjectName,
+    ObjectNamePart, Offset, OnConflict, OnConflictAction, OnInsert, OpenStatement, OrderBy,
+    OrderByExpr, OrderByKind, Partition, PivotValueSource, ProjectionSelect, Query, RaiseStatement,
+    RaiseStatementValue, ReferentialAction, RenameSelectItem, ReplaceSelectElement,
+    ReplaceSelectItem, Select, SelectInto, SelectItem, SetExpr, SqlOption, Statement, Subscript,
+    SymbolDefinition, TableAlias, TableAliasColumnDef, TableConstraint, TableFactor, TableObject,
+    TableOptionsClustered, TableWithJoins, UpdateTableFromKind, Use, Value, Values, ViewColumnDef,
+    WhileStatement, WildcardAdditionalOptions, With, WithFill,
 };
 
 /// Given an iterator of spans, return the [Span::union] of all spans.
@@ -89,6 +89,53 @@ pub trait Spanned {
     ///
     /// [`Location`]: crate::tokenizer::Location
     fn span(&self) -> Span;
+
+    /// Return the fragment of `sql` covered by this node's [`Span`], where
+    /// `sql` is the original text the node was parsed from.
+    ///
+    /// Returns `None` if the node has no span information, or if the span
+    /// does not lie within `sql`.
+    ///
+    /// # Example
+    /// ```
+    /// # use sqlparser::parser::{Parser, ParserError};
+    /// # use sqlparser::ast::Spanned;
+    /// # use sqlparser::dialect::GenericDialect;
+    /// # fn main() -> Result<(), ParserError> {
+    /// let sql = "SELECT a + 1\n  FROM t";
+    /// let statements = Parser::parse_sql(&GenericDialect {}, sql)?;
+    /// assert_eq!(statements[0].source_fragment(sql), Some(sql));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn source_fragment<'a>(&self, sql: &'a str) -> Option<&'a str> {
+        let span = self.span();
+        if span == Span::empty() {
+            return None;
+        }
+        let start = location_to_byte_offset(sql, span.start)?;
+        let end = location_to_byte_offset(sql, span.end)?;
+        sql.get(start..end)
+    }
+}
+
+/// Convert a 1-based line and column [`Location`] into a byte offset into `sql`.
+///
+/// A location one past the last character maps to `sql.len()`.
+fn location_to_byte_offset(sql: &str, location: Location) -> Option<usize> {
+    let (mut line, mut column) = (1, 1);
+    for (offset, ch) in sql.char_indices() {
+        if line == location.line && column == location.column {
+            return Some(offset);
+        }
+        if ch == '\n' {
+            line += 1;
+            column = 1;
+        } else {
+            column += 1;
+        }
+    }
+    (line == location.line && column == location.column).then_some(sql.len())
 }
 
 impl Spanned for Query {
@@ -238,6 +285,7 @@ impl Spanned for Values {
 /// - [Statement::AlterType]
 /// - [Statement::AlterRole]
 /// - [Statement::AttachDatabase]
+/// - [Statement::DetachDatabase]
 /// - [Statement::AttachDuckDBDatabase]
 /// - [Statement::DetachDuckDBDatabase]
 /// - [Statement::Drop]
@@ -256,6 +304,7 @@ impl Spanned for Values {
 /// - [Statement::ShowVariables]
 /// - [Statement::ShowCreate]
 /// - [Statement::ShowColumns]
+/// - [Statement::ShowIndex]
 /// - [Statement::ShowTables]
 /// - [Statement::ShowCollation]
 /// - [Statement::StartTransaction]
@@ -291,6 +340,7 @@ impl Spanned for Values {
 /// - [Statement::UnlockTables]
 /// - [Statement::Unload]
 /// - [Statement::OptimizeTable]
+/// - [Statement::InsertMultiple]
 impl Spanned for Statement {
     fn span(&self) -> Span {
         match self {
@@ -401,6 +451,7 @@ impl Spanned for Statement {
                 temporary: _,
                 to,
                 params: _,
+                with_check_option: _,
             } => union_spans(
                 core::iter::once(name.span())
                     .chain(columns.iter().map(|i| i.span()))
@@ -455,6 +506,7 @@ impl Spanned for Statement {
             Statement::AlterRole { .. } => Span::empty(),
             Statement::AlterSession { .. } => Span::empty(),
             Statement::AttachDatabase { .. } => Span::empty(),
+            Statement::DetachDatabase { .. } => Span::empty(),
             Statement::AttachDuckDBDatabase { .. } => Span::empty(),
             Statement::DetachDuckDBDatabase { .. } => Span::empty(),
             Statement::Drop { .. } => Span::empty(),
@@ -469,12 +521,14 @@ impl Spanned for Statement {
             Statement::Flush { .. } => Span::empty(),
             Statement::Discard { .. } => Span::empty(),
             Statement::Set(_) => Span::empty(),
+            Statement::Reset(_) => Span::empty(),
             Statement::ShowFunctions { .. } => Span::empty(),
             Statement::ShowVariable { .. } => Span::empty(),
             Statement::ShowStatus { .. } => Span::empty(),
             Statement::ShowVariables { .. } => Span::empty(),
             Statement::ShowCreate { .. } => Span::empty(),
             Statement::ShowColumns { .. } => Span::empty(),
+            Statement::ShowIndex { .. } => Span::empty(),
             Statement::ShowTables { .. } => Span::empty(),
             Statement::ShowCollation { .. } => Span::empty(),
             Statement::Use(u) => u.span(),
@@ -486,6 +540,7 @@ impl Spanned for Statement {
             Statement::CreateDatabase { .. } => Span::empty(),
             Statement::CreateFunction { .. } => Span::empty(),
             Statement::CreateDomain { .. } => Span::empty(),
+            Statement::CreateAggregate(_) => Span::empty(),
             Statement::CreateTrigger { .. } => Span::empty(),
             Statement::DropTrigger { .. } => Span::empty(),
             Statement::CreateProcedure { .. } => Span::empty(),
@@ -513,6 +568,7 @@ impl Spanned for Statement {
             Statement::UnlockTables => Span::empty(),
             Statement::Unload { .. } => Span::empty(),
             Statement::OptimizeTable { .. } => Span::empty(),
+            Statement::InsertMultiple(_) => Span::empty(),
             Statement::CreatePolicy { .. } => Span::empty(),
             Statement::AlterPolicy { .. } => Span::empty(),
             Statement::AlterConnector { .. } => Span::empty(),
@@ -532,6 +588,7 @@ impl Spanned for Statement {
             Statement::Return { .. } => Span::empty(),
             Statement::List(..) | Statement::Remove(..) => Span::empty(),
             Statement::CreateUser(..) => Span::empty(),
+            Statement::AlterUser(..) => Span::empty(),
         }
     }
 }
@@ -576,6 +633,8 @@ impl Spanned for CreateTable {
             location: _,          // string, no span
             query,
             without_rowid: _, // bool
+            period_for_system_time: _,
+            with_system_versioning: _, // bool
             like,
             clone,
             comment: _, // todo, no span
@@ -584,6 +643,9 @@ impl Spanned for CreateTable {
             primary_key: _,                     // todo, clickhouse specific
             order_by: _,                        // todo, clickhouse specific
             partition_by: _,                    // todo, BigQuery specific
+            partition_strategy: _,              // todo, PostgreSQL specific
+            partition_of: _,                    // todo, PostgreSQL specific
+            for_values: _,                      // todo, PostgreSQL specific
             cluster_by: _,                      // todo, BigQuery specific
             clustered_by: _,                    // todo, Hive specific
             inherits: _,                        // todo, PostgreSQL specific
@@ -611,7 +673,11 @@ impl Spanned for CreateTable {
                 .chain(columns.iter().map(|i| i.span()))
                 .chain(constraints.iter().map(|i| i.span()))
                 .chain(query.iter().map(|i| i.span()))
-                .chain(like.iter().map(|i| i.span()))
+                .chain(like.iter().map(|like| match like {
+                    CreateTableLikeKind::Parenthesized(like) | CreateTableLikeKind::Plain(like) => {
+                        like.name.span()
+                    }
+                }))
                 .chain(clone.iter().map(|i| i.span())),
         )
     }
@@ -1119,6 +1185,12 @@ impl Spanned for AlterTableOperation {
             } => union_spans(column_names.iter().map(|i| i.span)),
             AlterTableOperation::AttachPartition { partition } => partition.span(),
             AlterTableOperation::DetachPartition { partition } => partition.span(),
+            AlterTableOperation::AttachTablePartition { partition_name, .. } => {
+                partition_name.span()
+            }
+            AlterTableOperation::DetachTablePartition { partition_name, .. } => {
+                partition_name.span()
+            }
             AlterTableOperation::FreezePartition {
                 partition,
                 with_name,
@@ -1197,6 +1269,8 @@ impl Spanned for AlterTableOperation {
             AlterTableOperation::DropClusteringKey => Span::empty(),
             AlterTableOperation::SuspendRecluster => Span::empty(),
             AlterTableOperation::ResumeRecluster => Span::empty(),
+            AlterTableOperation::SetTags { tags } => union_spans(tags.iter().map(|t| t.key.span())),
+            AlterTableOperation::UnsetTags { keys } => union_spans(keys.iter().map(|k| k.span())),
             AlterTableOperation::Algorithm { .. } => Span::empty(),
             AlterTableOperation::AutoIncrement { value, .. } => value.span(),
             AlterTableOperation::Lock { .. } => Span::empty(),
@@ -1702,6 +1776,7 @@ impl Spanned for Function {
             parameters,
             args,
             filter,
+            from_first: _,
             null_treatment: _, // enum
             over: _,           // todo
             within_group,
@@ -2228,6 +2303,7 @@ impl Spanned for Select {
             into,
             from,
             lateral_views,
+            array_joins,
             prewhere,
             selection,
             group_by,
@@ -2250,6 +2326,7 @@ impl Spanned for Select {
                 .chain(into.iter().map(|item| item.span()))
                 .chain(from.iter().map(|item| item.span()))
                 .chain(lateral_views.iter().map(|item| item.span()))
+                .chain(array_joins.iter().map(|item| item.span()))
                 .chain(prewhere.iter().map(|item| item.span()))
                 .chain(selection.iter().map(|item| item.span()))
                 .chain(core::iter::once(group_by.span()))
@@ -2269,6 +2346,7 @@ impl Spanned for ConnectBy {
         let ConnectBy {
             condition,
             relationships,
+            nocycle: _,
         } = self;
 
         union_spans(
@@ -2288,6 +2366,14 @@ impl Spanned for NamedWindowDefinition {
     }
 }
 
+impl Spanned for ArrayJoin {
+    fn span(&self) -> Span {
+        let ArrayJoin { left: _, exprs } = self;
+
+        union_spans(exprs.iter().map(|item| item.span()))
+    }
+}
+
 impl Spanned for LateralView {
     fn span(&self) -> Span {
         let LateralView {
@@ -2501,6 +2587,37 @@ pub mod tests {
         assert_eq!(test.get_source(body_span), "SELECT cte.* FROM cte");
     }
 
+    #[test]
+    fn test_source_fragment() {
+        let dialect = &GenericDialect;
+        let sql = "SELECT id, name\n  FROM users\n  WHERE nämé = 'ü' AND id > 1";
+        let statements = Parser::parse_sql(dialect, sql).unwrap();
+        let statement = &statements[0];
+        assert_eq!(statement.source_fragment(sql), Some(sql));
+
+        let Statement::Query(query) = statement else {
+            unreachable!()
+        };
+        let SetExpr::Select(select) = query.body.as_ref() else {
+            unreachable!()
+        };
+        let selection = select.selection.as_ref().unwrap();
+        assert_eq!(
+            selection.source_fragment(sql),
+            Some("nämé = 'ü' AND id > 1")
+        );
+        let Expr::BinaryOp { right, .. } = selection else {
+            unreachable!()
+        };
+        assert_eq!(right.source_fragment(sql), Some("id > 1"));
+
+        // nodes without span information have no source fragment
+        assert_eq!(
+            Expr::Identifier(crate::ast::Ident::new("id")).source_fragment(sql),
+            None
+        );
+    }
+
     #[test]
     fn test_case_expr_span() {
         let dialect = &GenericDialect;
@@ -333,6 +333,20 @@ pub enum DateTimeField {
     TimezoneMinute,
     TimezoneRegion,
     NoDateTime,
+    /// MySQL compound units, e.g. `INTERVAL '2:30' HOUR_MINUTE`
+    ///
+    /// [MySQL](https://dev.mysql.com/doc/refman/8.4/en/expressions.html#temporal-intervals)
+    SecondMicrosecond,
+    MinuteMicrosecond,
+    MinuteSecond,
+    HourMicrosecond,
+    HourSecond,
+    HourMinute,
+    DayMicrosecond,
+    DaySecond,
+    DayMinute,
+    DayHour,
+    YearMonth,
     /// Arbitrary abbreviation or custom date-time part.
     ///
     /// ```sql
@@ -394,6 +408,17 @@ impl fmt::Display for DateTimeField {
             DateTimeField::TimezoneMinute => write!(f, "TIMEZONE_MINUTE"),
             DateTimeField::TimezoneRegion => write!(f, "TIMEZONE_REGION"),
             DateTimeField::NoDateTime => write!(f, "NODATETIME"),
+            DateTimeField::SecondMicrosecond => write!(f, "SECOND_MICROSECOND"),
+            DateTimeField::MinuteMicrosecond => write!(f, "MINUTE_MICROSECOND"),
+            DateTimeField::MinuteSecond => write!(f, "MINUTE_SECOND"),
+            DateTimeField::HourMicrosecond => write!(f, "HOUR_MICROSECOND"),
+            DateTimeField::HourSecond => write!(f, "HOUR_SECOND"),
+            DateTimeField::HourMinute => write!(f, "HOUR_MINUTE"),
+            DateTimeField::DayMicrosecond => write!(f, "DAY_MICROSECOND"),
+            DateTimeField::DaySecond => write!(f, "DAY_SECOND"),
+            DateTimeField::DayMinute => write!(f, "DAY_MINUTE"),
+            DateTimeField::DayHour => write!(f, "DAY_HOUR"),
+            DateTimeField::YearMonth => write!(f, "YEAR_MONTH"),
             DateTimeField::Custom(custom) => write!(f, "{custom}"),
         }
     }
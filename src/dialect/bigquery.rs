@@ -137,8 +137,8 @@ impl Dialect for BigQueryDialect {
         true
     }
 
-    fn is_column_alias(&self, kw: &Keyword, _parser: &mut Parser) -> bool {
-        !RESERVED_FOR_COLUMN_ALIAS.contains(kw)
+    fn get_reserved_keywords_for_column_alias(&self) -> &[Keyword] {
+        RESERVED_FOR_COLUMN_ALIAS
     }
 
     fn supports_pipe_operator(&self) -> bool {
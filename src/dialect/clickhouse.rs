@@ -16,6 +16,8 @@
 // under the License.
 
 use crate::dialect::Dialect;
+use crate::keywords::{self, Keyword};
+use crate::parser::Parser;
 
 // A [`Dialect`] for [ClickHouse](https://clickhouse.com/).
 #[derive(Debug)]
@@ -94,4 +96,20 @@ impl Dialect for ClickHouseDialect {
     fn supports_group_by_with_modifier(&self) -> bool {
         true
     }
+
+    /// `FORMAT` and `SETTINGS` after a select item start query clauses rather than aliases.
+    /// See <https://clickhouse.com/docs/en/sql-reference/statements/select/format>
+    fn is_reserved_for_column_alias(&self, kw: &Keyword) -> bool {
+        matches!(kw, Keyword::FORMAT | Keyword::SETTINGS)
+            || keywords::RESERVED_FOR_COLUMN_ALIAS.contains(kw)
+    }
+
+    /// `ARRAY JOIN` after a table starts an array join rather than an alias.
+    /// See <https://clickhouse.com/docs/en/sql-reference/statements/select/array-join>
+    fn is_table_alias(&self, kw: &Keyword, parser: &mut Parser) -> bool {
+        if *kw == Keyword::ARRAY && parser.peek_keyword(Keyword::JOIN) {
+            return false;
+        }
+        !self.is_reserved_for_table_alias(kw)
+    }
 }
@@ -144,6 +144,10 @@ impl Dialect for GenericDialect {
         true
     }
 
+    fn supports_timestamp_versioning(&self) -> bool {
+        true
+    }
+
     fn supports_empty_projections(&self) -> bool {
         true
     }
@@ -67,6 +67,10 @@ impl Dialect for HiveDialect {
         true
     }
 
+    fn supports_table_sample_after_alias(&self) -> bool {
+        false
+    }
+
     /// See <https://cwiki.apache.org/confluence/pages/viewpage.action?pageId=30151323#EnhancedAggregation,Cube,GroupingandRollup-CubesandRollupsr>
     fn supports_group_by_with_modifier(&self) -> bool {
         true
@@ -47,6 +47,7 @@ pub use self::mssql::MsSqlDialect;
 pub use self::mysql::MySqlDialect;
 pub use self::postgresql::PostgreSqlDialect;
 pub use self::redshift::RedshiftSqlDialect;
+pub(crate) use self::snowflake::parse_snowflake_stage_name;
 pub use self::snowflake::SnowflakeDialect;
 pub use self::sqlite::SQLiteDialect;
 use crate::ast::{ColumnOption, Expr, GranteesType, Ident, ObjectNamePart, Statement};
@@ -957,10 +958,19 @@ pub trait Dialect: Debug + Any {
         false
     }
 
+    /// Returns the keywords that are reserved and cannot be used as an
+    /// identifier without special handling like quoting.
+    ///
+    /// Dialects may still accept some of these as identifiers by overriding
+    /// [Self::is_reserved_for_identifier], which is what the parser checks.
+    fn reserved_keywords(&self) -> &[Keyword] {
+        keywords::RESERVED_FOR_IDENTIFIER
+    }
+
     /// Returns true if the specified keyword is reserved and cannot be
     /// used as an identifier without special handling like quoting.
     fn is_reserved_for_identifier(&self, kw: Keyword) -> bool {
-        keywords::RESERVED_FOR_IDENTIFIER.contains(&kw)
+        self.reserved_keywords().contains(&kw)
     }
 
     /// Returns reserved keywords when looking to parse a `TableFactor`.
@@ -975,6 +985,39 @@ pub trait Dialect: Debug + Any {
         &[]
     }
 
+    /// Returns keywords that cannot be used as a column alias without `AS`,
+    /// e.g. `SELECT 1 <kw>`.
+    ///
+    /// Keywords that are only reserved depending on the tokens following
+    /// them (e.g. `LIMIT` in Snowflake) are not included, the parser checks
+    /// those with [Self::is_column_alias].
+    fn get_reserved_keywords_for_column_alias(&self) -> &[Keyword] {
+        keywords::RESERVED_FOR_COLUMN_ALIAS
+    }
+
+    /// Returns keywords that cannot be used as a table alias without `AS`,
+    /// e.g. `SELECT * FROM t <kw>`.
+    ///
+    /// Keywords that are only reserved depending on the tokens following
+    /// them are not included, the parser checks those with [Self::is_table_alias].
+    fn get_reserved_keywords_for_table_alias(&self) -> &[Keyword] {
+        keywords::RESERVED_FOR_TABLE_ALIAS
+    }
+
+    /// Returns true if the specified keyword cannot be used as a column alias
+    /// without `AS`, regardless of the tokens following it.
+    /// See [Self::get_reserved_keywords_for_column_alias]
+    fn is_reserved_for_column_alias(&self, kw: &Keyword) -> bool {
+        self.get_reserved_keywords_for_column_alias().contains(kw)
+    }
+
+    /// Returns true if the specified keyword cannot be used as a table alias
+    /// without `AS`, regardless of the tokens following it.
+    /// See [Self::get_reserved_keywords_for_table_alias]
+    fn is_reserved_for_table_alias(&self, kw: &Keyword) -> bool {
+        self.get_reserved_keywords_for_table_alias().contains(kw)
+    }
+
     /// Returns grantee types that should be treated as identifiers
     fn get_reserved_grantees_types(&self) -> &[GranteesType] {
         &[]
@@ -983,14 +1026,34 @@ pub trait Dialect: Debug + Any {
     /// Returns true if this dialect supports the `TABLESAMPLE` option
     /// before the table alias option. For example:
     ///
-    /// Table sample before alias: `SELECT * FROM tbl AS t TABLESAMPLE (10)`
-    /// Table sample after alias: `SELECT * FROM tbl TABLESAMPLE (10) AS t`
+    /// Table sample before alias: `SELECT * FROM tbl TABLESAMPLE (10) AS t`
+    /// Table sample after alias: `SELECT * FROM tbl AS t TABLESAMPLE (10)`
+    ///
+    /// See [Self::supports_table_sample_after_alias] for the other placement.
     ///
     /// <https://jakewheat.github.io/sql-overview/sql-2016-foundation-grammar.html#_7_6_table_reference>
     fn supports_table_sample_before_alias(&self) -> bool {
         false
     }
 
+    /// Returns true if this dialect supports the `TABLESAMPLE` option
+    /// after the table alias option. For example:
+    /// `SELECT * FROM tbl AS t TABLESAMPLE (10)`
+    ///
+    /// A dialect may support both placements, see [Self::supports_table_sample_before_alias].
+    fn supports_table_sample_after_alias(&self) -> bool {
+        true
+    }
+
+    /// Returns true if this dialect supports querying staged files by
+    /// referencing a stage in the `FROM` clause. For example:
+    /// `SELECT $1, $2 FROM @my_stage/path (FILE_FORMAT => 'my_format')`
+    ///
+    /// [Snowflake](https://docs.snowflake.com/en/user-guide/querying-stage)
+    fn supports_select_from_stage(&self) -> bool {
+        false
+    }
+
     /// Returns true if this dialect supports the `INSERT INTO ... SET col1 = 1, ...` syntax.
     ///
     /// MySQL: <https://dev.mysql.com/doc/refman/8.4/en/insert.html>
@@ -1015,9 +1078,9 @@ pub trait Dialect: Debug + Any {
     }
 
     /// Returns true if the specified keyword should be parsed as a column identifier.
-    /// See [keywords::RESERVED_FOR_COLUMN_ALIAS]
+    /// See [Self::is_reserved_for_column_alias]
     fn is_column_alias(&self, kw: &Keyword, _parser: &mut Parser) -> bool {
-        !keywords::RESERVED_FOR_COLUMN_ALIAS.contains(kw)
+        !self.is_reserved_for_column_alias(kw)
     }
 
     /// Returns true if the specified keyword should be parsed as a select item alias.
@@ -1028,9 +1091,9 @@ pub trait Dialect: Debug + Any {
     }
 
     /// Returns true if the specified keyword should be parsed as a table identifier.
-    /// See [keywords::RESERVED_FOR_TABLE_ALIAS]
+    /// See [Self::is_reserved_for_table_alias]
     fn is_table_alias(&self, kw: &Keyword, _parser: &mut Parser) -> bool {
-        !keywords::RESERVED_FOR_TABLE_ALIAS.contains(kw)
+        !self.is_reserved_for_table_alias(kw)
     }
 
     /// Returns true if the specified keyword should be parsed as a table factor alias.
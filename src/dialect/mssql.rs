@@ -128,8 +128,8 @@ impl Dialect for MsSqlDialect {
         &[GranteesType::Public]
     }
 
-    fn is_column_alias(&self, kw: &Keyword, _parser: &mut Parser) -> bool {
-        !keywords::RESERVED_FOR_COLUMN_ALIAS.contains(kw) && !RESERVED_FOR_COLUMN_ALIAS.contains(kw)
+    fn is_reserved_for_column_alias(&self, kw: &Keyword) -> bool {
+        keywords::RESERVED_FOR_COLUMN_ALIAS.contains(kw) || RESERVED_FOR_COLUMN_ALIAS.contains(kw)
     }
 
     fn parse_statement(&self, parser: &mut Parser) -> Option<Result<Statement, ParserError>> {
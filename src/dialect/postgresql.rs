@@ -215,6 +215,16 @@ impl Dialect for PostgreSqlDialect {
         true
     }
 
+    /// See <https://www.postgresql.org/docs/current/sql-syntax-calling-funcs.html#SQL-SYNTAX-CALLING-FUNCS-NAMED>
+    ///
+    /// Required to support the older named notation in:
+    /// ```sql
+    /// SELECT make_interval(days := 10)
+    /// ```
+    fn supports_named_fn_args_with_assignment_operator(&self) -> bool {
+        true
+    }
+
     /// See <https://www.postgresql.org/docs/current/functions-json.html>
     ///
     /// Required to support the label in:
@@ -25,15 +25,17 @@ use crate::ast::helpers::stmt_data_loading::{
     FileStagingCommand, StageLoadSelectItem, StageLoadSelectItemKind, StageParamsObject,
 };
 use crate::ast::{
-    ColumnOption, ColumnPolicy, ColumnPolicyProperty, CopyIntoSnowflakeKind, DollarQuotedString,
-    Ident, IdentityParameters, IdentityProperty, IdentityPropertyFormatKind, IdentityPropertyKind,
-    IdentityPropertyOrder, ObjectName, ObjectNamePart, RowAccessPolicy, ShowObjects, SqlOption,
-    Statement, TagsColumnOption, WrappedCollection,
+    ColumnOption, ColumnPolicy, ColumnPolicyProperty, CopyIntoSnowflakeKind, CreateTableLike,
+    CreateTableLikeKind, DollarQuotedString, Ident, IdentityParameters, IdentityProperty,
+    IdentityPropertyFormatKind, IdentityPropertyKind, IdentityPropertyOrder, InsertMultiple,
+    InsertMultipleIntoClause, InsertMultipleKind, InsertMultipleWhenClause, ObjectName,
+    ObjectNamePart, RowAccessPolicy, ShowObjects, SqlOption, Statement, TagsColumnOption,
+    WrappedCollection,
 };
 use crate::dialect::{Dialect, Precedence};
 use crate::keywords::Keyword;
 use crate::parser::{IsOptional, Parser, ParserError};
-use crate::tokenizer::Token;
+use crate::tokenizer::{Token, Word};
 #[cfg(not(feature = "std"))]
 use alloc::boxed::Box;
 #[cfg(not(feature = "std"))]
@@ -47,6 +49,86 @@ use super::keywords::RESERVED_FOR_IDENTIFIER;
 use sqlparser::ast::StorageSerializationPolicy;
 
 const RESERVED_KEYWORDS_FOR_SELECT_ITEM_OPERATOR: [Keyword; 1] = [Keyword::CONNECT_BY_ROOT];
+
+/// Copy options accepted by `COPY INTO <table>`.
+/// See: <https://docs.snowflake.com/en/sql-reference/sql/copy-into-table#copy-options-copyoptions>
+const COPY_INTO_TABLE_OPTIONS: &[&str] = &[
+    "ON_ERROR",
+    "SIZE_LIMIT",
+    "PURGE",
+    "RETURN_FAILED_ONLY",
+    "MATCH_BY_COLUMN_NAME",
+    "INCLUDE_METADATA",
+    "ENFORCE_LENGTH",
+    "TRUNCATECOLUMNS",
+    "FORCE",
+    "LOAD_UNCERTAIN_FILES",
+    "FILE_PROCESSOR",
+    "LOAD_MODE",
+];
+
+/// Copy options accepted by `COPY INTO <location>`.
+/// See: <https://docs.snowflake.com/en/sql-reference/sql/copy-into-location#copy-options-copyoptions>
+const COPY_INTO_LOCATION_OPTIONS: &[&str] = &[
+    "OVERWRITE",
+    "SINGLE",
+    "MAX_FILE_SIZE",
+    "INCLUDE_QUERY_ID",
+    "DETAILED_OUTPUT",
+    "HEADER",
+];
+
+/// Reserved keywords by the Snowflake dialect, which seem to be less strictive
+/// than what is listed in `keywords::RESERVED_FOR_COLUMN_ALIAS`. The following
+/// keywords were tested with the this statement: `SELECT 1 <KW>`.
+const RESERVED_FOR_COLUMN_ALIAS: &[Keyword] = &[
+    Keyword::FROM,
+    Keyword::GROUP,
+    Keyword::HAVING,
+    Keyword::INTERSECT,
+    Keyword::INTO,
+    Keyword::MINUS,
+    Keyword::ORDER,
+    Keyword::SELECT,
+    Keyword::UNION,
+    Keyword::WHERE,
+    Keyword::WITH,
+];
+
+/// Reserved keywords by the Snowflake dialect, which seem to be less strictive
+/// than what is listed in `keywords::RESERVED_FOR_TABLE_ALIAS`. The following
+/// keywords were tested with the this statement: `SELECT <KW>.* FROM tbl <KW>`.
+const RESERVED_FOR_TABLE_ALIAS: &[Keyword] = &[
+    Keyword::WITH,
+    Keyword::ORDER,
+    Keyword::SELECT,
+    Keyword::WHERE,
+    Keyword::GROUP,
+    Keyword::HAVING,
+    Keyword::LATERAL,
+    Keyword::UNION,
+    Keyword::INTERSECT,
+    Keyword::MINUS,
+    Keyword::ON,
+    Keyword::JOIN,
+    Keyword::INNER,
+    Keyword::CROSS,
+    Keyword::FULL,
+    Keyword::LEFT,
+    Keyword::RIGHT,
+    Keyword::NATURAL,
+    Keyword::USING,
+    Keyword::ASOF,
+    Keyword::MATCH_CONDITION,
+    Keyword::SET,
+    Keyword::QUALIFY,
+    Keyword::FOR,
+    Keyword::START,
+    Keyword::CONNECT,
+    Keyword::SAMPLE,
+    Keyword::TABLESAMPLE,
+    Keyword::FROM,
+];
 /// A [`Dialect`] for [Snowflake](https://www.snowflake.com/)
 #[derive(Debug, Default)]
 pub struct SnowflakeDialect;
@@ -198,6 +280,34 @@ impl Dialect for SnowflakeDialect {
                 }
             }
         }
+        if parser.parse_keyword(Keyword::INSERT) {
+            // possibly multi-table INSERT [ OVERWRITE ] { ALL | FIRST }
+            let overwrite = parser.parse_keyword(Keyword::OVERWRITE);
+            match parser.parse_one_of_keywords(&[Keyword::ALL, Keyword::FIRST]) {
+                Some(Keyword::ALL) => {
+                    return Some(parse_insert_multiple(
+                        overwrite,
+                        InsertMultipleKind::All,
+                        parser,
+                    ))
+                }
+                Some(Keyword::FIRST) => {
+                    return Some(parse_insert_multiple(
+                        overwrite,
+                        InsertMultipleKind::First,
+                        parser,
+                    ))
+                }
+                _ => {
+                    // need to go back with the cursor
+                    if overwrite {
+                        parser.prev_token();
+                    }
+                    parser.prev_token();
+                }
+            }
+        }
+
         if parser.parse_keywords(&[Keyword::COPY, Keyword::INTO]) {
             // COPY INTO
             return Some(parse_copy_into(parser));
@@ -299,10 +409,23 @@ impl Dialect for SnowflakeDialect {
         }
     }
 
+    fn get_reserved_keywords_for_column_alias(&self) -> &[Keyword] {
+        RESERVED_FOR_COLUMN_ALIAS
+    }
+
+    fn get_reserved_keywords_for_table_alias(&self) -> &[Keyword] {
+        RESERVED_FOR_TABLE_ALIAS
+    }
+
     fn supports_partiql(&self) -> bool {
         true
     }
 
+    /// See <https://docs.snowflake.com/en/user-guide/querying-stage>
+    fn supports_select_from_stage(&self) -> bool {
+        true
+    }
+
     fn is_column_alias(&self, kw: &Keyword, parser: &mut Parser) -> bool {
         match kw {
             // The following keywords can be considered an alias as long as 
@@ -329,23 +452,9 @@ impl Dialect for SnowflakeDialect {
                 false
             }
 
-            // Reserved keywords by the Snowflake dialect, which seem to be less strictive 
-            // than what is listed in `keywords::RESERVED_FOR_COLUMN_ALIAS`. The following 
-            // keywords were tested with the this statement: `SELECT 1 <KW>`.
-            Keyword::FROM
-            | Keyword::GROUP
-            | Keyword::HAVING
-            | Keyword::INTERSECT
-            | Keyword::INTO
-            | Keyword::MINUS
-            | Keyword::ORDER
-            | Keyword::SELECT
-            | Keyword::UNION
-            | Keyword::WHERE
-            | Keyword::WITH => false,
-
-            // Any other word is considered an alias
-            _ => true,
+            // Any other word is considered an alias unless it is reserved,
+            // see `RESERVED_FOR_COLUMN_ALIAS`
+            _ => !self.is_reserved_for_column_alias(kw),
         }
     }
 
@@ -395,41 +504,26 @@ impl Dialect for SnowflakeDialect {
 
             Keyword::GLOBAL if parser.peek_keyword(Keyword::FULL) => false,
 
-            // Reserved keywords by the Snowflake dialect, which seem to be less strictive
-            // than what is listed in `keywords::RESERVED_FOR_TABLE_ALIAS`. The following
-            // keywords were tested with the this statement: `SELECT <KW>.* FROM tbl <KW>`.
-            Keyword::WITH
-            | Keyword::ORDER
-            | Keyword::SELECT
-            | Keyword::WHERE
-            | Keyword::GROUP
-            | Keyword::HAVING
-            | Keyword::LATERAL
-            | Keyword::UNION
-            | Keyword::INTERSECT
-            | Keyword::MINUS
-            | Keyword::ON
-            | Keyword::JOIN
-            | Keyword::INNER
-            | Keyword::CROSS
-            | Keyword::FULL
-            | Keyword::LEFT
-            | Keyword::RIGHT
-            | Keyword::NATURAL
-            | Keyword::USING
-            | Keyword::ASOF
-            | Keyword::MATCH_CONDITION
-            | Keyword::SET
-            | Keyword::QUALIFY
-            | Keyword::FOR
-            | Keyword::START
-            | Keyword::CONNECT
-            | Keyword::SAMPLE
-            | Keyword::TABLESAMPLE
-            | Keyword::FROM => false,
-
-            // Any other word is considered an alias
-            _ => true,
+            // `WINDOW` can be considered an alias unless it starts a named window
+            // definition, for example: `SELECT * FROM tbl WINDOW w AS (ORDER BY a)`
+            Keyword::WINDOW
+                if matches!(
+                    parser.peek_tokens(),
+                    [
+                        Token::Word(_),
+                        Token::Word(Word {
+                            keyword: Keyword::AS,
+                            ..
+                        })
+                    ]
+                ) =>
+            {
+                false
+            }
+
+            // Any other word is considered an alias unless it is reserved,
+            // see `RESERVED_FOR_TABLE_ALIAS`
+            _ => !self.is_reserved_for_table_alias(kw),
         }
     }
 
@@ -580,7 +674,12 @@ pub fn parse_create_table(
                     builder = builder.clone_clause(clone);
                 }
                 Keyword::LIKE => {
-                    let like = parser.parse_object_name(false).ok();
+                    let like = parser.parse_object_name(false).ok().map(|name| {
+                        CreateTableLikeKind::Plain(CreateTableLike {
+                            name,
+                            options: vec![],
+                        })
+                    });
                     builder = builder.like(like);
                 }
                 Keyword::CLUSTER => {
@@ -827,12 +926,13 @@ pub fn parse_stage_name_identifier(parser: &mut Parser) -> Result<Ident, ParserE
     let mut ident = String::new();
     while let Some(next_token) = parser.next_token_no_skip() {
         match &next_token.token {
-            Token::Whitespace(_) | Token::SemiColon => break,
-            Token::Period => {
+            Token::Whitespace(_) => break,
+            // leave the statement terminator for the caller
+            Token::Period | Token::SemiColon => {
                 parser.prev_token();
                 break;
             }
-            Token::RParen => {
+            Token::LParen | Token::RParen => {
                 parser.prev_token();
                 break;
             }
@@ -841,6 +941,10 @@ pub fn parse_stage_name_identifier(parser: &mut Parser) -> Result<Ident, ParserE
             Token::Mod => ident.push('%'),
             Token::Div => ident.push('/'),
             Token::Plus => ident.push('+'),
+            Token::Minus => ident.push('-'),
+            Token::Eq => ident.push('='),
+            Token::Ampersand => ident.push('&'),
+            Token::Placeholder(p) => ident.push_str(p),
             Token::Number(n, _) => ident.push_str(n),
             Token::Word(w) => ident.push_str(&w.to_string()),
             _ => return parser.expected("stage name identifier", parser.peek_token()),
@@ -869,6 +973,84 @@ pub fn parse_snowflake_stage_name(parser: &mut Parser) -> Result<ObjectName, Par
     }
 }
 
+/// Parses a multi-table `INSERT { ALL | FIRST }` statement, starting after
+/// the `ALL` or `FIRST` keyword.
+///
+/// See <https://docs.snowflake.com/en/sql-reference/sql/insert-multi-table>
+fn parse_insert_multiple(
+    overwrite: bool,
+    kind: InsertMultipleKind,
+    parser: &mut Parser,
+) -> Result<Statement, ParserError> {
+    let mut into_clauses = vec![];
+    let mut when_clauses = vec![];
+    let mut else_clauses = None;
+
+    if parser.peek_keyword(Keyword::WHEN) {
+        while parser.parse_keyword(Keyword::WHEN) {
+            let condition = parser.parse_expr()?;
+            parser.expect_keyword_is(Keyword::THEN)?;
+            let into_clauses = parse_insert_multiple_into_clauses(parser)?;
+            when_clauses.push(InsertMultipleWhenClause {
+                condition,
+                into_clauses,
+            });
+        }
+        if parser.parse_keyword(Keyword::ELSE) {
+            else_clauses = Some(parse_insert_multiple_into_clauses(parser)?);
+        }
+    } else if kind == InsertMultipleKind::All {
+        into_clauses = parse_insert_multiple_into_clauses(parser)?;
+    } else {
+        return parser.expected("WHEN", parser.peek_token());
+    }
+
+    let source = parser.parse_query()?;
+
+    Ok(Statement::InsertMultiple(InsertMultiple {
+        overwrite,
+        kind,
+        into_clauses,
+        when_clauses,
+        else_clauses,
+        source,
+    }))
+}
+
+/// Parses one or more `INTO <table> [ (<columns>) ] [ VALUES (<exprs>) ]` clauses.
+fn parse_insert_multiple_into_clauses(
+    parser: &mut Parser,
+) -> Result<Vec<InsertMultipleIntoClause>, ParserError> {
+    let mut clauses = vec![];
+    loop {
+        parser.expect_keyword_is(Keyword::INTO)?;
+        let table_name = parser.parse_object_name(false)?;
+        // the column list is ambiguous with a parenthesized source query
+        let columns = parser
+            .maybe_parse(|parser| {
+                parser.parse_parenthesized_column_list(IsOptional::Mandatory, false)
+            })?
+            .unwrap_or_default();
+        let values = if parser.parse_keyword(Keyword::VALUES) {
+            parser.expect_token(&Token::LParen)?;
+            let values = parser.parse_comma_separated(Parser::parse_expr)?;
+            parser.expect_token(&Token::RParen)?;
+            Some(values)
+        } else {
+            None
+        };
+        clauses.push(InsertMultipleIntoClause {
+            table_name,
+            columns,
+            values,
+        });
+        if !parser.peek_keyword(Keyword::INTO) {
+            break;
+        }
+    }
+    Ok(clauses)
+}
+
 /// Parses a `COPY INTO` statement. Snowflake has two variants, `COPY INTO <table>`
 /// and `COPY INTO <location>` which have different syntax.
 pub fn parse_copy_into(parser: &mut Parser) -> Result<Statement, ParserError> {
@@ -995,15 +1177,39 @@ pub fn parse_copy_into(parser: &mut Parser) -> Result<Statement, ParserError> {
         // COPY OPTIONS
         } else if parser.parse_keyword(Keyword::COPY_OPTIONS) {
             parser.expect_token(&Token::Eq)?;
-            copy_options = parser.parse_key_value_options(true, &[])?;
+            // Keep any options given outside of `COPY_OPTIONS`, in their original order
+            copy_options.extend(parser.parse_key_value_options(true, &[])?);
         } else {
-            match parser.next_token().token {
-                Token::SemiColon | Token::EOF => break,
+            let next_token = parser.next_token();
+            match next_token.token {
+                Token::SemiColon | Token::EOF => {
+                    parser.prev_token();
+                    break;
+                }
                 Token::Comma => continue,
                 // In `COPY INTO <location>` the copy options do not have a shared key
                 // like in `COPY INTO <table>`
-                Token::Word(key) => copy_options.push(parser.parse_key_value_option(key)?),
-                _ => return parser.expected("another copy option, ; or EOF'", parser.peek_token()),
+                Token::Word(key) => {
+                    let known_options = match kind {
+                        CopyIntoSnowflakeKind::Table => COPY_INTO_TABLE_OPTIONS,
+                        CopyIntoSnowflakeKind::Location => COPY_INTO_LOCATION_OPTIONS,
+                    };
+                    if !known_options
+                        .iter()
+                        .any(|option| key.value.eq_ignore_ascii_case(option))
+                    {
+                        parser.prev_token();
+                        return parser.expected("a copy option", parser.peek_token());
+                    }
+                    if parser.peek_token().token != Token::Eq {
+                        return parser.expected(
+                            &format!("= after copy option {}", key.value),
+                            parser.peek_token(),
+                        );
+                    }
+                    copy_options.push(parser.parse_key_value_option(key)?)
+                }
+                _ => return parser.expected("another copy option, ; or EOF", next_token),
             }
         }
     }
@@ -90,6 +90,7 @@ define_keywords!(
     ALIAS,
     ALL,
     ALLOCATE,
+    ALLOW_CONNECTIONS,
     ALTER,
     ALWAYS,
     ANALYZE,
@@ -200,6 +201,7 @@ define_keywords!(
     COLUMNS,
     COLUMNSTORE,
     COMMENT,
+    COMMENTS,
     COMMIT,
     COMMITTED,
     COMPATIBLE,
@@ -213,6 +215,8 @@ define_keywords!(
     CONNECTOR,
     CONNECT_BY_ROOT,
     CONSTRAINT,
+    CONSTRAINTS,
+    CONTAINED,
     CONTAINS,
     CONTINUE,
     CONVERT,
@@ -257,6 +261,10 @@ define_keywords!(
     DAYOFWEEK,
     DAYOFYEAR,
     DAYS,
+    DAY_HOUR,
+    DAY_MICROSECOND,
+    DAY_MINUTE,
+    DAY_SECOND,
     DCPROPERTIES,
     DEALLOCATE,
     DEC,
@@ -265,6 +273,7 @@ define_keywords!(
     DECLARE,
     DEDUPLICATE,
     DEFAULT,
+    DEFAULTS,
     DEFAULT_DDL_COLLATION,
     DEFERRABLE,
     DEFERRED,
@@ -336,6 +345,7 @@ define_keywords!(
     EXCEPTION,
     EXCHANGE,
     EXCLUDE,
+    EXCLUDING,
     EXCLUSIVE,
     EXEC,
     EXECUTE,
@@ -363,6 +373,7 @@ define_keywords!(
     FILL,
     FILTER,
     FINAL,
+    FINALIZE,
     FIRST,
     FIRST_VALUE,
     FIXEDSTRING,
@@ -422,6 +433,9 @@ define_keywords!(
     HOSTS,
     HOUR,
     HOURS,
+    HOUR_MICROSECOND,
+    HOUR_MINUTE,
+    HOUR_SECOND,
     HUGEINT,
     ICEBERG,
     ID,
@@ -437,8 +451,10 @@ define_keywords!(
     IN,
     INCLUDE,
     INCLUDE_NULL_VALUES,
+    INCLUDING,
     INCREMENT,
     INDEX,
+    INDEXES,
     INDICATOR,
     INHERIT,
     INHERITS,
@@ -478,6 +494,7 @@ define_keywords!(
     ISOLATION,
     ISOWEEK,
     ISOYEAR,
+    IS_TEMPLATE,
     ITEMS,
     JAR,
     JOIN,
@@ -496,6 +513,8 @@ define_keywords!(
     LAST,
     LAST_VALUE,
     LATERAL,
+    LC_COLLATE,
+    LC_CTYPE,
     LEAD,
     LEADING,
     LEFT,
@@ -511,6 +530,7 @@ define_keywords!(
     LN,
     LOAD,
     LOCAL,
+    LOCALE,
     LOCALTIME,
     LOCALTIMESTAMP,
     LOCATION,
@@ -563,6 +583,8 @@ define_keywords!(
     MINUS,
     MINUTE,
     MINUTES,
+    MINUTE_MICROSECOND,
+    MINUTE_SECOND,
     MINVALUE,
     MIN_ROWS,
     MOD,
@@ -570,10 +592,12 @@ define_keywords!(
     MODIFIES,
     MODIFY,
     MODULE,
+    MODULUS,
     MONITOR,
     MONTH,
     MONTHS,
     MSCK,
+    MSTYPE,
     MULTISET,
     MUTATION,
     NAME,
@@ -596,6 +620,7 @@ define_keywords!(
     NOBYPASSRLS,
     NOCREATEDB,
     NOCREATEROLE,
+    NOCYCLE,
     NOINHERIT,
     NOLOGIN,
     NONE,
@@ -649,6 +674,7 @@ define_keywords!(
     ORDINALITY,
     ORGANIZATION,
     OTHER,
+    OTHERS,
     OUT,
     OUTER,
     OUTPUT,
@@ -749,6 +775,7 @@ define_keywords!(
     RELAY,
     RELEASE,
     RELEASES,
+    REMAINDER,
     REMOTE,
     REMOVE,
     RENAME,
@@ -804,6 +831,7 @@ define_keywords!(
     SECONDARY,
     SECONDARY_ENGINE_ATTRIBUTE,
     SECONDS,
+    SECOND_MICROSECOND,
     SECRET,
     SECURITY,
     SEED,
@@ -870,9 +898,11 @@ define_keywords!(
     STORAGE_SERIALIZATION_POLICY,
     STORED,
     STRAIGHT_JOIN,
+    STRATEGY,
     STRICT,
     STRING,
     STRUCT,
+    STYPE,
     SUBMULTISET,
     SUBSTR,
     SUBSTRING,
@@ -898,6 +928,7 @@ define_keywords!(
     TASK,
     TBLPROPERTIES,
     TEMP,
+    TEMPLATE,
     TEMPORARY,
     TEMPTABLE,
     TERMINATED,
@@ -1027,6 +1058,7 @@ define_keywords!(
     XOR,
     YEAR,
     YEARS,
+    YEAR_MONTH,
     ZONE,
     ZORDER
 );
@@ -13,13 +13,14 @@
 //! SQL Parser for ALTER
 
 #[cfg(not(feature = "std"))]
-use alloc::vec;
+use alloc::{vec, vec::Vec};
 
 use super::{Parser, ParserError};
 use crate::{
     ast::{
-        AlterConnectorOwner, AlterPolicyOperation, AlterRoleOperation, Expr, Password, ResetConfig,
-        RoleOption, SetConfigValue, Statement,
+        helpers::key_value_options::{KeyValueOptions, KeyValueOptionsDelimiter},
+        AlterConnectorOwner, AlterPolicyOperation, AlterRoleOperation, AlterUser,
+        AlterUserOperation, Expr, Password, ResetConfig, RoleOption, SetConfigValue, Statement,
     },
     dialect::{MsSqlDialect, PostgreSqlDialect},
     keywords::Keyword,
@@ -39,6 +40,98 @@ impl Parser<'_> {
         ))
     }
 
+    /// Parse `ALTER USER` statement
+    /// ```sql
+    /// ALTER USER [ IF EXISTS ] name RENAME TO new_name
+    /// ALTER USER [ IF EXISTS ] name SET property = value [ ... ]
+    /// ALTER USER [ IF EXISTS ] name UNSET property [ , ... ]
+    /// ALTER USER name [ WITH ] option [ ... ]
+    /// ALTER USER name SET configuration_parameter { TO | = } { value | DEFAULT }
+    /// ALTER USER name SET configuration_parameter FROM CURRENT
+    /// ALTER USER name RESET { configuration_parameter | ALL }
+    /// ```
+    ///
+    /// [Snowflake](https://docs.snowflake.com/en/sql-reference/sql/alter-user)
+    /// [PostgreSQL](https://www.postgresql.org/docs/current/sql-alteruser.html)
+    pub fn parse_alter_user(&mut self) -> Result<Statement, ParserError> {
+        let if_exists = self.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
+        let name = self.parse_identifier()?;
+
+        let operation = if self.parse_keywords(&[Keyword::RENAME, Keyword::TO]) {
+            AlterUserOperation::RenameTo(self.parse_identifier()?)
+        } else if dialect_of!(self is PostgreSqlDialect) && self.parse_keyword(Keyword::SET) {
+            let config_name = self.parse_object_name(false)?;
+            let config_value = if self.parse_keywords(&[Keyword::FROM, Keyword::CURRENT]) {
+                SetConfigValue::FromCurrent
+            } else if self.consume_token(&Token::Eq) || self.parse_keyword(Keyword::TO) {
+                if self.parse_keyword(Keyword::DEFAULT) {
+                    SetConfigValue::Default
+                } else {
+                    SetConfigValue::Value(self.parse_expr()?)
+                }
+            } else {
+                return self.expected("'TO' or '=' or 'FROM CURRENT'", self.peek_token());
+            };
+            AlterUserOperation::SetConfig {
+                config_name,
+                config_value,
+            }
+        } else if dialect_of!(self is PostgreSqlDialect) && self.parse_keyword(Keyword::RESET) {
+            if self.parse_keyword(Keyword::ALL) {
+                AlterUserOperation::Reset(ResetConfig::ALL)
+            } else {
+                AlterUserOperation::Reset(ResetConfig::ConfigName(self.parse_object_name(false)?))
+            }
+        } else if let Some(options) = self.maybe_parse_pg_alter_user_options()? {
+            AlterUserOperation::WithOptions(options)
+        } else if self.parse_keyword(Keyword::SET) {
+            let options = self.parse_key_value_options(false, &[])?;
+            if options.is_empty() {
+                return self.expected("option", self.peek_token())?;
+            }
+            AlterUserOperation::Set(KeyValueOptions {
+                options,
+                delimiter: KeyValueOptionsDelimiter::Space,
+            })
+        } else if self.parse_keyword(Keyword::UNSET) {
+            AlterUserOperation::Unset(self.parse_comma_separated(Parser::parse_identifier)?)
+        } else {
+            let expected = if dialect_of!(self is PostgreSqlDialect) {
+                "RENAME, SET, RESET or a role option after ALTER USER"
+            } else {
+                "RENAME, SET or UNSET after ALTER USER"
+            };
+            return self.expected(expected, self.peek_token());
+        };
+
+        Ok(Statement::AlterUser(AlterUser {
+            if_exists,
+            name,
+            operation,
+        }))
+    }
+
+    /// Parse the PostgreSQL `[ WITH ] option [ ... ]` form of `ALTER USER`,
+    /// returning `None` (and consuming nothing) if no role option follows.
+    fn maybe_parse_pg_alter_user_options(
+        &mut self,
+    ) -> Result<Option<Vec<RoleOption>>, ParserError> {
+        if !dialect_of!(self is PostgreSqlDialect) {
+            return Ok(None);
+        }
+        self.maybe_parse(|parser| {
+            let _ = parser.parse_keyword(Keyword::WITH);
+            let mut options = vec![];
+            while let Some(opt) = parser.maybe_parse(|parser| parser.parse_pg_role_option())? {
+                options.push(opt);
+            }
+            if options.is_empty() {
+                return parser.expected("option", parser.peek_token());
+            }
+            Ok(options)
+        })
+    }
+
     /// Parse ALTER POLICY statement
     /// ```sql
     /// ALTER POLICY policy_name ON table_name [ RENAME TO new_name ]
@@ -250,7 +343,7 @@ impl Parser<'_> {
         })
     }
 
-    fn parse_pg_role_option(&mut self) -> Result<RoleOption, ParserError> {
+    pub(crate) fn parse_pg_role_option(&mut self) -> Result<RoleOption, ParserError> {
         let option = match self.parse_one_of_keywords(&[
             Keyword::BYPASSRLS,
             Keyword::NOBYPASSRLS,
@@ -22,6 +22,7 @@ use alloc::{
 };
 use core::{
     fmt::{self, Display},
+    ops::ControlFlow,
     str::FromStr,
 };
 use helpers::attached_token::AttachedToken;
@@ -273,6 +274,14 @@ impl ParserOptions {
     }
 }
 
+/// The columns, table constraints and `PERIOD FOR SYSTEM_TIME` definition
+/// of a parenthesized `CREATE TABLE` column list.
+type ColumnList = (
+    Vec<ColumnDef>,
+    Vec<TableConstraint>,
+    Option<PeriodForSystemTime>,
+);
+
 #[derive(Copy, Clone)]
 enum ParserState {
     /// The default state of the parser.
@@ -287,6 +296,13 @@ enum ParserState {
     /// CREATE TABLE foo (abc BIGINT NOT NULL);
     /// ```
     ColumnDefinition,
+    /// The state when parsing the column list of a PostgreSQL
+    /// `CREATE TABLE ... PARTITION OF`. Columns there carry only options,
+    /// their data types are inherited from the parent table. For example:
+    /// ```sql
+    /// CREATE TABLE c PARTITION OF p (a NOT NULL) FOR VALUES IN (1);
+    /// ```
+    PartitionOfColumns,
 }
 
 /// A SQL Parser
@@ -475,7 +491,78 @@ impl<'a> Parser<'a> {
     /// # }
     /// ```
     pub fn parse_statements(&mut self) -> Result<Vec<Statement>, ParserError> {
+        Ok(self
+            .parse_statements_with_locations()?
+            .into_iter()
+            .map(|(statement, _)| statement)
+            .collect())
+    }
+
+    /// Parse potentially multiple statements, returning each statement along
+    /// with the [`Span`] it covers in the source.
+    ///
+    /// The span of a statement starts at its first token and ends at its
+    /// last token, excluding the terminating semicolon.
+    ///
+    /// Example
+    /// ```
+    /// # use sqlparser::{parser::{Parser, ParserError}, dialect::GenericDialect};
+    /// # use sqlparser::tokenizer::{Location, Span};
+    /// # fn main() -> Result<(), ParserError> {
+    /// let dialect = GenericDialect{};
+    /// let statements = Parser::new(&dialect)
+    ///   .try_with_sql("SELECT 1; SELECT 2")?
+    ///   .parse_statements_with_locations()?;
+    /// assert_eq!(statements[1].1, Span::new(Location::new(1, 11), Location::new(1, 19)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_statements_with_locations(
+        &mut self,
+    ) -> Result<Vec<(Statement, Span)>, ParserError> {
         let mut stmts = Vec::new();
+        self.parse_statements_with(|statement, span| {
+            stmts.push((statement, span));
+            ControlFlow::Continue(())
+        })?;
+        Ok(stmts)
+    }
+
+    /// Parse potentially multiple statements, handing each one to `f` as soon
+    /// as it is parsed instead of collecting them into a `Vec`.
+    ///
+    /// Parsing stops early, without error, if `f` returns [`ControlFlow::Break`].
+    ///
+    /// Example
+    /// ```
+    /// # use core::ops::ControlFlow;
+    /// # use sqlparser::{parser::{Parser, ParserError}, dialect::GenericDialect};
+    /// # fn main() -> Result<(), ParserError> {
+    /// let dialect = GenericDialect{};
+    /// let mut count = 0;
+    /// Parser::new(&dialect)
+    ///   .try_with_sql("SELECT 1; SELECT 2; SELECT 3")?
+    ///   .parse_statements_for_each(|_statement| {
+    ///     count += 1;
+    ///     ControlFlow::Continue(())
+    ///   })?;
+    /// assert_eq!(count, 3);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_statements_for_each<F>(&mut self, mut f: F) -> Result<(), ParserError>
+    where
+        F: FnMut(Statement) -> ControlFlow<()>,
+    {
+        self.parse_statements_with(|statement, _| f(statement))
+    }
+
+    /// Parse statements until the end of input, or until `f` returns
+    /// [`ControlFlow::Break`], passing each statement and its [`Span`] to `f`.
+    fn parse_statements_with<F>(&mut self, mut f: F) -> Result<(), ParserError>
+    where
+        F: FnMut(Statement, Span) -> ControlFlow<()>,
+    {
         let mut expecting_statement_delimiter = false;
         loop {
             // ignore empty statements (between successive statement delimiters)
@@ -503,11 +590,22 @@ impl<'a> Parser<'a> {
                 return self.expected("end of statement", self.peek_token());
             }
 
+            let start = self.peek_token_ref().span.start;
             let statement = self.parse_statement()?;
-            stmts.push(statement);
+            // the parser may have stepped back over trailing whitespace
+            let end = self
+                .tokens
+                .iter()
+                .take(self.index)
+                .rev()
+                .find(|t| !matches!(t.token, Token::Whitespace(_)))
+                .map_or(start, |t| t.span.end);
+            if f(statement, Span::new(start, end)).is_break() {
+                break;
+            }
             expecting_statement_delimiter = true;
         }
-        Ok(stmts)
+        Ok(())
     }
 
     /// Convenience method to parse a string with one or more SQL
@@ -568,6 +666,11 @@ impl<'a> Parser<'a> {
                     self.prev_token();
                     self.parse_query().map(Statement::Query)
                 }
+                // `TABLE t` shorthand for `SELECT * FROM t`
+                Keyword::TABLE if dialect_of!(self is PostgreSqlDialect | GenericDialect) => {
+                    self.prev_token();
+                    self.parse_query().map(Statement::Query)
+                }
                 Keyword::TRUNCATE => self.parse_truncate(),
                 Keyword::ATTACH => {
                     if dialect_of!(self is DuckDbDialect) {
@@ -579,6 +682,9 @@ impl<'a> Parser<'a> {
                 Keyword::DETACH if dialect_of!(self is DuckDbDialect | GenericDialect) => {
                     self.parse_detach_duckdb_database()
                 }
+                Keyword::DETACH if dialect_of!(self is SQLiteDialect) => {
+                    self.parse_detach_database()
+                }
                 Keyword::MSCK => self.parse_msck(),
                 Keyword::CREATE => self.parse_create(),
                 Keyword::CACHE => self.parse_cache_table(),
@@ -600,6 +706,7 @@ impl<'a> Parser<'a> {
                 }
                 Keyword::CLOSE => self.parse_close(),
                 Keyword::SET => self.parse_set(),
+                Keyword::RESET => self.parse_reset(),
                 Keyword::SHOW => self.parse_show(),
                 Keyword::USE => self.parse_use(),
                 Keyword::GRANT => self.parse_grant(),
@@ -1111,6 +1218,16 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// Parse a SQLite `DETACH [DATABASE] schema_name` statement.
+    pub fn parse_detach_database(&mut self) -> Result<Statement, ParserError> {
+        let database = self.parse_keyword(Keyword::DATABASE);
+        let schema_name = self.parse_identifier()?;
+        Ok(Statement::DetachDatabase {
+            schema_name,
+            database,
+        })
+    }
+
     pub fn parse_analyze(&mut self) -> Result<Statement, ParserError> {
         let has_table_keyword = self.parse_keyword(Keyword::TABLE);
         let table_name = self.parse_object_name(false)?;
@@ -1363,6 +1480,7 @@ impl<'a> Parser<'a> {
                         uses_odbc_syntax: false,
                         parameters: FunctionArguments::None,
                         args: FunctionArguments::None,
+                        from_first: None,
                         null_treatment: None,
                         filter: None,
                         over: None,
@@ -1426,6 +1544,7 @@ impl<'a> Parser<'a> {
                         parameters: FunctionArguments::None,
                         args: FunctionArguments::Subquery(query),
                         filter: None,
+                        from_first: None,
                         null_treatment: None,
                         over: None,
                         within_group: vec![],
@@ -2064,6 +2183,7 @@ impl<'a> Parser<'a> {
                 parameters: FunctionArguments::None,
                 args: FunctionArguments::Subquery(subquery),
                 filter: None,
+                from_first: None,
                 null_treatment: None,
                 over: None,
                 within_group: vec![],
@@ -2092,10 +2212,9 @@ impl<'a> Parser<'a> {
         };
 
         let filter = if self.dialect.supports_filter_during_aggregation()
-            && self.parse_keyword(Keyword::FILTER)
-            && self.consume_token(&Token::LParen)
-            && self.parse_keyword(Keyword::WHERE)
+            && self.parse_keyword_with_tokens(Keyword::FILTER, &[Token::LParen])
         {
+            self.expect_keyword_is(Keyword::WHERE)?;
             let filter = Some(Box::new(self.parse_expr()?));
             self.expect_token(&Token::RParen)?;
             filter
@@ -2103,6 +2222,8 @@ impl<'a> Parser<'a> {
             None
         };
 
+        let from_first = self.maybe_parse_function_from_first()?;
+
         // Syntax for null treatment shows up either in the args list
         // or after the function call, but not both.
         let null_treatment = if args
@@ -2131,6 +2252,7 @@ impl<'a> Parser<'a> {
             uses_odbc_syntax: false,
             parameters,
             args: FunctionArguments::List(args),
+            from_first,
             null_treatment,
             filter,
             over,
@@ -2138,6 +2260,34 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// Optionally parses `FROM FIRST` or `FROM LAST` after a function call
+    /// such as `NTH_VALUE(x, 2)`.
+    ///
+    /// The clause is only recognized when followed by a null treatment or
+    /// an `OVER` clause, so that e.g. `SELECT f(x) FROM first` still treats
+    /// `first` as a table.
+    fn maybe_parse_function_from_first(&mut self) -> Result<Option<bool>, ParserError> {
+        if !self.peek_keyword(Keyword::FROM) {
+            return Ok(None);
+        }
+        self.maybe_parse(|parser| {
+            parser.expect_keyword_is(Keyword::FROM)?;
+            let from_first =
+                parser.expect_one_of_keywords(&[Keyword::FIRST, Keyword::LAST])? == Keyword::FIRST;
+            match parser.peek_token().token {
+                Token::Word(w)
+                    if matches!(
+                        w.keyword,
+                        Keyword::IGNORE | Keyword::RESPECT | Keyword::OVER
+                    ) =>
+                {
+                    Ok(from_first)
+                }
+                _ => parser.expected("IGNORE, RESPECT or OVER", parser.peek_token()),
+            }
+        })
+    }
+
     /// Optionally parses a null treatment clause.
     fn parse_null_treatment(&mut self) -> Result<Option<NullTreatment>, ParserError> {
         match self.parse_one_of_keywords(&[Keyword::RESPECT, Keyword::IGNORE]) {
@@ -2167,6 +2317,7 @@ impl<'a> Parser<'a> {
             args,
             filter: None,
             over: None,
+            from_first: None,
             null_treatment: None,
             within_group: vec![],
         }))
@@ -2195,13 +2346,37 @@ impl<'a> Parser<'a> {
         } else {
             (self.parse_window_frame_bound()?, None)
         };
+        let exclusion = if self.parse_keyword(Keyword::EXCLUDE) {
+            Some(self.parse_window_frame_exclusion()?)
+        } else {
+            None
+        };
         Ok(WindowFrame {
             units,
             start_bound,
             end_bound,
+            exclusion,
         })
     }
 
+    /// Parse `CURRENT ROW`, `GROUP`, `TIES` or `NO OTHERS` following `EXCLUDE`
+    fn parse_window_frame_exclusion(&mut self) -> Result<WindowFrameExclusion, ParserError> {
+        if self.parse_keywords(&[Keyword::CURRENT, Keyword::ROW]) {
+            Ok(WindowFrameExclusion::CurrentRow)
+        } else if self.parse_keyword(Keyword::GROUP) {
+            Ok(WindowFrameExclusion::Group)
+        } else if self.parse_keyword(Keyword::TIES) {
+            Ok(WindowFrameExclusion::Ties)
+        } else if self.parse_keywords(&[Keyword::NO, Keyword::OTHERS]) {
+            Ok(WindowFrameExclusion::NoOthers)
+        } else {
+            self.expected(
+                "CURRENT ROW, GROUP, TIES or NO OTHERS after EXCLUDE",
+                self.peek_token(),
+            )
+        }
+    }
+
     /// Parse `CURRENT ROW` or `{ <positive number> | UNBOUNDED } { PRECEDING | FOLLOWING }`
     pub fn parse_window_frame_bound(&mut self) -> Result<WindowFrameBound, ParserError> {
         if self.parse_keywords(&[Keyword::CURRENT, Keyword::ROW]) {
@@ -2230,7 +2405,20 @@ impl<'a> Parser<'a> {
         if self.dialect.supports_group_by_expr() {
             if self.parse_keywords(&[Keyword::GROUPING, Keyword::SETS]) {
                 self.expect_token(&Token::LParen)?;
-                let result = self.parse_comma_separated(|p| p.parse_tuple(false, true))?;
+                let result = self.parse_comma_separated(|p| {
+                    // `ROLLUP` and `CUBE` may be nested directly within grouping sets
+                    if matches!(
+                        p.peek_token_ref().token,
+                        Token::Word(Word {
+                            keyword: Keyword::ROLLUP | Keyword::CUBE,
+                            ..
+                        })
+                    ) {
+                        Ok(vec![p.parse_group_by_expr()?])
+                    } else {
+                        p.parse_tuple(false, true)
+                    }
+                })?;
                 self.expect_token(&Token::RParen)?;
                 Ok(Expr::GroupingSets(result))
             } else if self.parse_keyword(Keyword::CUBE) {
@@ -2581,6 +2769,17 @@ impl<'a> Parser<'a> {
         if let Token::Word(word) = self.peek_token().token {
             if [Keyword::BOTH, Keyword::LEADING, Keyword::TRAILING].contains(&word.keyword) {
                 trim_where = Some(self.parse_trim_where()?);
+                // `TRIM(LEADING FROM expr)` without trim characters
+                if self.parse_keyword(Keyword::FROM) {
+                    let expr = self.parse_expr()?;
+                    self.expect_token(&Token::RParen)?;
+                    return Ok(Expr::Trim {
+                        expr: Box::new(expr),
+                        trim_where,
+                        trim_what: None,
+                        trim_characters: None,
+                    });
+                }
             }
         }
         let expr = self.parse_expr()?;
@@ -2732,6 +2931,17 @@ impl<'a> Parser<'a> {
                 Keyword::TIMEZONE_HOUR => Ok(DateTimeField::TimezoneHour),
                 Keyword::TIMEZONE_MINUTE => Ok(DateTimeField::TimezoneMinute),
                 Keyword::TIMEZONE_REGION => Ok(DateTimeField::TimezoneRegion),
+                Keyword::SECOND_MICROSECOND => Ok(DateTimeField::SecondMicrosecond),
+                Keyword::MINUTE_MICROSECOND => Ok(DateTimeField::MinuteMicrosecond),
+                Keyword::MINUTE_SECOND => Ok(DateTimeField::MinuteSecond),
+                Keyword::HOUR_MICROSECOND => Ok(DateTimeField::HourMicrosecond),
+                Keyword::HOUR_SECOND => Ok(DateTimeField::HourSecond),
+                Keyword::HOUR_MINUTE => Ok(DateTimeField::HourMinute),
+                Keyword::DAY_MICROSECOND => Ok(DateTimeField::DayMicrosecond),
+                Keyword::DAY_SECOND => Ok(DateTimeField::DaySecond),
+                Keyword::DAY_MINUTE => Ok(DateTimeField::DayMinute),
+                Keyword::DAY_HOUR => Ok(DateTimeField::DayHour),
+                Keyword::YEAR_MONTH => Ok(DateTimeField::YearMonth),
                 _ if self.dialect.allow_extract_custom() => {
                     self.prev_token();
                     let custom = self.parse_identifier()?;
@@ -2967,7 +3177,21 @@ impl<'a> Parser<'a> {
                     | Keyword::TIMEZONE
                     | Keyword::TIMEZONE_HOUR
                     | Keyword::TIMEZONE_MINUTE
-            )
+            ) || (dialect_of!(self is MySqlDialect | GenericDialect)
+                && matches!(
+                    word.keyword,
+                    Keyword::SECOND_MICROSECOND
+                        | Keyword::MINUTE_MICROSECOND
+                        | Keyword::MINUTE_SECOND
+                        | Keyword::HOUR_MICROSECOND
+                        | Keyword::HOUR_SECOND
+                        | Keyword::HOUR_MINUTE
+                        | Keyword::DAY_MICROSECOND
+                        | Keyword::DAY_SECOND
+                        | Keyword::DAY_MINUTE
+                        | Keyword::DAY_HOUR
+                        | Keyword::YEAR_MONTH
+                ))
         } else {
             false
         }
@@ -4677,6 +4901,10 @@ impl<'a> Parser<'a> {
             self.parse_create_function(or_alter, or_replace, temporary)
         } else if self.parse_keyword(Keyword::DOMAIN) {
             self.parse_create_domain()
+        } else if dialect_of!(self is PostgreSqlDialect | GenericDialect)
+            && self.parse_keyword(Keyword::AGGREGATE)
+        {
+            self.parse_create_aggregate(or_replace)
         } else if self.parse_keyword(Keyword::TRIGGER) {
             self.parse_create_trigger(or_alter, or_replace, false)
         } else if self.parse_keywords(&[Keyword::CONSTRAINT, Keyword::TRIGGER]) {
@@ -4724,6 +4952,16 @@ impl<'a> Parser<'a> {
     fn parse_create_user(&mut self, or_replace: bool) -> Result<Statement, ParserError> {
         let if_not_exists = self.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
         let name = self.parse_identifier()?;
+        let mut role_options = vec![];
+        if dialect_of!(self is PostgreSqlDialect) {
+            let with = self.parse_keyword(Keyword::WITH);
+            while let Some(opt) = self.maybe_parse(|parser| parser.parse_pg_role_option())? {
+                role_options.push(opt);
+            }
+            if with && role_options.is_empty() {
+                return self.expected("role option after WITH", self.peek_token());
+            }
+        }
         let options = self.parse_key_value_options(false, &[Keyword::WITH, Keyword::TAG])?;
         let with_tags = self.parse_keyword(Keyword::WITH);
         let tags = if self.parse_keyword(Keyword::TAG) {
@@ -4744,6 +4982,7 @@ impl<'a> Parser<'a> {
                 options: tags,
                 delimiter: KeyValueOptionsDelimiter::Comma,
             },
+            role_options,
         }))
     }
 
@@ -5002,6 +5241,11 @@ impl<'a> Parser<'a> {
         } else {
             None
         };
+        let options = if dialect_of!(self is PostgreSqlDialect | GenericDialect) {
+            self.parse_create_database_options()?
+        } else {
+            vec![]
+        };
 
         Ok(Statement::CreateDatabase {
             db_name,
@@ -5009,9 +5253,56 @@ impl<'a> Parser<'a> {
             location,
             managed_location,
             clone,
+            options,
         })
     }
 
+    /// Parses the options of a PostgreSQL `CREATE DATABASE` statement:
+    /// `[ WITH ] option [ = ] value [ ... ]`
+    fn parse_create_database_options(&mut self) -> Result<Vec<CreateDatabaseOption>, ParserError> {
+        let with = self.parse_keyword(Keyword::WITH);
+        let mut options = vec![];
+        while let Some(keyword) = self.parse_one_of_keywords(&[
+            Keyword::OWNER,
+            Keyword::TEMPLATE,
+            Keyword::ENCODING,
+            Keyword::STRATEGY,
+            Keyword::LOCALE,
+            Keyword::LC_COLLATE,
+            Keyword::LC_CTYPE,
+            Keyword::TABLESPACE,
+            Keyword::ALLOW_CONNECTIONS,
+            Keyword::CONNECTION,
+            Keyword::IS_TEMPLATE,
+        ]) {
+            if keyword == Keyword::CONNECTION {
+                self.expect_keyword_is(Keyword::LIMIT)?;
+            }
+            let _ = self.consume_token(&Token::Eq);
+            let option = match keyword {
+                Keyword::OWNER => CreateDatabaseOption::Owner(self.parse_identifier()?),
+                Keyword::TEMPLATE => CreateDatabaseOption::Template(self.parse_identifier()?),
+                Keyword::ENCODING => CreateDatabaseOption::Encoding(self.parse_expr()?),
+                Keyword::STRATEGY => CreateDatabaseOption::Strategy(self.parse_identifier()?),
+                Keyword::LOCALE => CreateDatabaseOption::Locale(self.parse_expr()?),
+                Keyword::LC_COLLATE => CreateDatabaseOption::LcCollate(self.parse_expr()?),
+                Keyword::LC_CTYPE => CreateDatabaseOption::LcCtype(self.parse_expr()?),
+                Keyword::TABLESPACE => CreateDatabaseOption::Tablespace(self.parse_identifier()?),
+                Keyword::ALLOW_CONNECTIONS => {
+                    CreateDatabaseOption::AllowConnections(self.parse_expr()?)
+                }
+                Keyword::CONNECTION => CreateDatabaseOption::ConnectionLimit(self.parse_expr()?),
+                Keyword::IS_TEMPLATE => CreateDatabaseOption::IsTemplate(self.parse_expr()?),
+                _ => unreachable!(),
+            };
+            options.push(option);
+        }
+        if with && options.is_empty() {
+            return self.expected("CREATE DATABASE option after WITH", self.peek_token());
+        }
+        Ok(options)
+    }
+
     pub fn parse_optional_create_function_using(
         &mut self,
     ) -> Result<Option<CreateFunctionUsing>, ParserError> {
@@ -5815,7 +6106,6 @@ impl<'a> Parser<'a> {
 
         self.expect_keyword_is(Keyword::AS)?;
         let query = self.parse_query()?;
-        // Optional `WITH [ CASCADED | LOCAL ] CHECK OPTION` is widely supported here.
 
         let with_no_schema_binding = dialect_of!(self is RedshiftSqlDialect | GenericDialect)
             && self.parse_keywords(&[
@@ -5825,6 +6115,13 @@ impl<'a> Parser<'a> {
                 Keyword::BINDING,
             ]);
 
+        let with_check_option = if dialect_of!(self is PostgreSqlDialect | MySqlDialect | GenericDialect)
+        {
+            self.parse_optional_check_option()
+        } else {
+            None
+        };
+
         Ok(Statement::CreateView {
             or_alter,
             name,
@@ -5840,9 +6137,33 @@ impl<'a> Parser<'a> {
             temporary,
             to,
             params: create_view_params,
+            with_check_option,
         })
     }
 
+    /// Parse the optional `WITH [ CASCADED | LOCAL ] CHECK OPTION` suffix of `CREATE VIEW`.
+    fn parse_optional_check_option(&mut self) -> Option<CheckOption> {
+        if self.parse_keywords(&[Keyword::WITH, Keyword::CHECK, Keyword::OPTION]) {
+            Some(CheckOption::Default)
+        } else if self.parse_keywords(&[
+            Keyword::WITH,
+            Keyword::LOCAL,
+            Keyword::CHECK,
+            Keyword::OPTION,
+        ]) {
+            Some(CheckOption::Local)
+        } else if self.parse_keywords(&[
+            Keyword::WITH,
+            Keyword::CASCADED,
+            Keyword::CHECK,
+            Keyword::OPTION,
+        ]) {
+            Some(CheckOption::Cascaded)
+        } else {
+            None
+        }
+    }
+
     /// Parse optional parameters for the `CREATE VIEW` statement supported by [MySQL].
     ///
     /// [MySQL]: https://dev.mysql.com/doc/refman/9.1/en/create-view.html
@@ -6172,6 +6493,44 @@ impl<'a> Parser<'a> {
         }))
     }
 
+    /// Parse a PostgreSQL `CREATE [ OR REPLACE ] AGGREGATE` statement.
+    ///
+    /// [PostgreSQL Documentation](https://www.postgresql.org/docs/current/sql-createaggregate.html)
+    fn parse_create_aggregate(&mut self, or_replace: bool) -> Result<Statement, ParserError> {
+        let name = self.parse_object_name(false)?;
+        self.expect_token(&Token::LParen)?;
+        let args = self.parse_comma_separated0(Parser::parse_function_arg, Token::RParen)?;
+        self.expect_token(&Token::RParen)?;
+        self.expect_token(&Token::LParen)?;
+        let options = self.parse_comma_separated(Parser::parse_create_aggregate_option)?;
+        self.expect_token(&Token::RParen)?;
+
+        Ok(Statement::CreateAggregate(CreateAggregate {
+            or_replace,
+            name,
+            args,
+            options,
+        }))
+    }
+
+    fn parse_create_aggregate_option(&mut self) -> Result<CreateAggregateOption, ParserError> {
+        // the state types are data types rather than expressions, e.g. `STYPE = float8[]`
+        let is_state_type = self
+            .peek_one_of_keywords(&[Keyword::STYPE, Keyword::MSTYPE])
+            .is_some();
+        let key = self.parse_identifier()?;
+        if !self.consume_token(&Token::Eq) {
+            return Ok(CreateAggregateOption::Flag(key));
+        }
+        if is_state_type {
+            let data_type = self.parse_data_type()?;
+            Ok(CreateAggregateOption::DataType { key, data_type })
+        } else {
+            let value = self.parse_expr()?;
+            Ok(CreateAggregateOption::KeyValue { key, value })
+        }
+    }
+
     /// ```sql
     ///     CREATE POLICY name ON table_name [ AS { PERMISSIVE | RESTRICTIVE } ]
     ///     [ FOR { ALL | SELECT | INSERT | UPDATE | DELETE } ]
@@ -6354,6 +6713,7 @@ impl<'a> Parser<'a> {
 
         let loc = self.peek_token().span.start;
         let cascade = self.parse_keyword(Keyword::CASCADE);
+        let cascade_constraints = cascade && self.parse_keyword(Keyword::CONSTRAINTS);
         let restrict = self.parse_keyword(Keyword::RESTRICT);
         let purge = self.parse_keyword(Keyword::PURGE);
         if cascade && restrict {
@@ -6376,6 +6736,7 @@ impl<'a> Parser<'a> {
             names,
             cascade,
             restrict,
+            cascade_constraints,
             purge,
             temporary,
             table,
@@ -6468,7 +6829,7 @@ impl<'a> Parser<'a> {
 
         let args = if self.consume_token(&Token::LParen) {
             if self.consume_token(&Token::RParen) {
-                None
+                Some(vec![])
             } else {
                 let args = self.parse_comma_separated(Parser::parse_function_arg)?;
                 self.expect_token(&Token::RParen)?;
@@ -7253,8 +7614,49 @@ impl<'a> Parser<'a> {
         // Clickhouse has `ON CLUSTER 'cluster'` syntax for DDLs
         let on_cluster = self.parse_optional_on_cluster()?;
 
+        // PostgreSQL supports
+        // `PARTITION OF parent [ ( column_list ) ] { FOR VALUES ... | DEFAULT }`
+        let (partition_of, partition_columns, for_values) = if dialect_of!(self is PostgreSqlDialect | GenericDialect)
+            && self.parse_keywords(&[Keyword::PARTITION, Keyword::OF])
+        {
+            let parent = self.parse_object_name(allow_unquoted_hyphen)?;
+            let columns =
+                self.with_state(ParserState::PartitionOfColumns, |p| p.parse_columns())?;
+            (
+                Some(parent),
+                Some(columns),
+                Some(self.parse_partition_for_values()?),
+            )
+        } else {
+            (None, None, None)
+        };
+
         let like = if self.parse_keyword(Keyword::LIKE) || self.parse_keyword(Keyword::ILIKE) {
-            self.parse_object_name(allow_unquoted_hyphen).ok()
+            self.parse_object_name(allow_unquoted_hyphen)
+                .ok()
+                .map(|name| {
+                    CreateTableLikeKind::Plain(CreateTableLike {
+                        name,
+                        options: vec![],
+                    })
+                })
+        } else if dialect_of!(self is PostgreSqlDialect | GenericDialect)
+            && self.peek_token_ref().token == Token::LParen
+            && matches!(
+                &self.peek_nth_token_ref(1).token,
+                Token::Word(w) if w.keyword == Keyword::LIKE
+            )
+        {
+            // PostgreSQL: `CREATE TABLE t (LIKE source [ like_option ... ])`
+            self.expect_token(&Token::LParen)?;
+            self.expect_keyword_is(Keyword::LIKE)?;
+            let name = self.parse_object_name(allow_unquoted_hyphen)?;
+            let options = self.parse_create_table_like_options()?;
+            self.expect_token(&Token::RParen)?;
+            Some(CreateTableLikeKind::Parenthesized(CreateTableLike {
+                name,
+                options,
+            }))
         } else {
             None
         };
@@ -7266,7 +7668,20 @@ impl<'a> Parser<'a> {
         };
 
         // parse optional column list (schema)
-        let (columns, constraints) = self.parse_columns()?;
+        let (columns, constraints, period_for_system_time) =
+            if let Some((columns, constraints)) = partition_columns {
+                (columns, constraints, None)
+            } else {
+                // MariaDB and MsSql support `PERIOD FOR SYSTEM_TIME` in the column list
+                self.parse_columns_inner(
+                    dialect_of!(self is MsSqlDialect | MySqlDialect | GenericDialect),
+                )?
+            };
+
+        // MariaDB supports `WITH SYSTEM VERSIONING` after the column list
+        let with_system_versioning = dialect_of!(self is MySqlDialect | GenericDialect)
+            && self.parse_keywords(&[Keyword::WITH, Keyword::SYSTEM, Keyword::VERSIONING]);
+
         let comment_after_column_def =
             if dialect_of!(self is HiveDialect) && self.parse_keyword(Keyword::COMMENT) {
                 let next_token = self.next_token();
@@ -7337,6 +7752,8 @@ impl<'a> Parser<'a> {
             .temporary(temporary)
             .columns(columns)
             .constraints(constraints)
+            .period_for_system_time(period_for_system_time)
+            .with_system_versioning(with_system_versioning)
             .or_replace(or_replace)
             .if_not_exists(if_not_exists)
             .transient(transient)
@@ -7353,6 +7770,9 @@ impl<'a> Parser<'a> {
             .on_cluster(on_cluster)
             .clustered_by(clustered_by)
             .partition_by(create_table_config.partition_by)
+            .partition_strategy(create_table_config.partition_strategy)
+            .partition_of(partition_of)
+            .for_values(for_values)
             .cluster_by(create_table_config.cluster_by)
             .inherits(create_table_config.inherits)
             .table_options(create_table_config.table_options)
@@ -7361,6 +7781,52 @@ impl<'a> Parser<'a> {
             .build())
     }
 
+    /// Parse the partition bound of a PostgreSQL `CREATE TABLE ... PARTITION OF` statement.
+    ///
+    /// See <https://www.postgresql.org/docs/current/sql-createtable.html>
+    fn parse_partition_for_values(&mut self) -> Result<ForValues, ParserError> {
+        if self.parse_keyword(Keyword::DEFAULT) {
+            return Ok(ForValues::Default);
+        }
+        self.expect_keywords(&[Keyword::FOR, Keyword::VALUES])?;
+        if self.parse_keyword(Keyword::IN) {
+            self.expect_token(&Token::LParen)?;
+            let values = self.parse_comma_separated(Parser::parse_expr)?;
+            self.expect_token(&Token::RParen)?;
+            Ok(ForValues::In(values))
+        } else if self.parse_keyword(Keyword::FROM) {
+            self.expect_token(&Token::LParen)?;
+            let from = self.parse_comma_separated(Parser::parse_partition_bound_value)?;
+            self.expect_token(&Token::RParen)?;
+            self.expect_keyword_is(Keyword::TO)?;
+            self.expect_token(&Token::LParen)?;
+            let to = self.parse_comma_separated(Parser::parse_partition_bound_value)?;
+            self.expect_token(&Token::RParen)?;
+            Ok(ForValues::From { from, to })
+        } else if self.parse_keyword(Keyword::WITH) {
+            self.expect_token(&Token::LParen)?;
+            self.expect_keyword_is(Keyword::MODULUS)?;
+            let modulus = self.parse_literal_uint()?;
+            self.expect_token(&Token::Comma)?;
+            self.expect_keyword_is(Keyword::REMAINDER)?;
+            let remainder = self.parse_literal_uint()?;
+            self.expect_token(&Token::RParen)?;
+            Ok(ForValues::With { modulus, remainder })
+        } else {
+            self.expected("IN, FROM or WITH after FOR VALUES", self.peek_token())
+        }
+    }
+
+    fn parse_partition_bound_value(&mut self) -> Result<PartitionBoundValue, ParserError> {
+        if self.parse_keyword(Keyword::MINVALUE) {
+            Ok(PartitionBoundValue::MinValue)
+        } else if self.parse_keyword(Keyword::MAXVALUE) {
+            Ok(PartitionBoundValue::MaxValue)
+        } else {
+            Ok(PartitionBoundValue::Expr(self.parse_expr()?))
+        }
+    }
+
     pub(crate) fn parse_create_table_on_commit(&mut self) -> Result<OnCommit, ParserError> {
         if self.parse_keywords(&[Keyword::DELETE, Keyword::ROWS]) {
             Ok(OnCommit::DeleteRows)
@@ -7402,13 +7868,20 @@ impl<'a> Parser<'a> {
         if !table_properties.is_empty() {
             table_options = CreateTableOptions::TableProperties(table_properties);
         }
-        let partition_by = if dialect_of!(self is BigQueryDialect | PostgreSqlDialect | GenericDialect)
+        let mut partition_by = None;
+        let mut partition_strategy = None;
+        if dialect_of!(self is BigQueryDialect | PostgreSqlDialect | GenericDialect)
             && self.parse_keywords(&[Keyword::PARTITION, Keyword::BY])
         {
-            Some(Box::new(self.parse_expr()?))
-        } else {
-            None
-        };
+            partition_strategy = if dialect_of!(self is PostgreSqlDialect | GenericDialect) {
+                self.maybe_parse_partition_strategy()?
+            } else {
+                None
+            };
+            if partition_strategy.is_none() {
+                partition_by = Some(Box::new(self.parse_expr()?));
+            }
+        }
 
         let mut cluster_by = None;
         if dialect_of!(self is BigQueryDialect | GenericDialect) {
@@ -7435,12 +7908,42 @@ impl<'a> Parser<'a> {
 
         Ok(CreateTableConfiguration {
             partition_by,
+            partition_strategy,
             cluster_by,
             inherits,
             table_options,
         })
     }
 
+    /// Parse a PostgreSQL partitioning strategy following `PARTITION BY`, returning
+    /// `None` if the next tokens are not `{ RANGE | LIST | HASH } (`.
+    ///
+    /// See <https://www.postgresql.org/docs/current/ddl-partitioning.html>
+    fn maybe_parse_partition_strategy(&mut self) -> Result<Option<PartitionStrategy>, ParserError> {
+        let strategy = match self.peek_tokens_ref::<2>() {
+            [TokenWithSpan {
+                token: Token::Word(w),
+                ..
+            }, TokenWithSpan {
+                token: Token::LParen,
+                ..
+            }] => w.keyword,
+            _ => return Ok(None),
+        };
+        if !matches!(strategy, Keyword::RANGE | Keyword::LIST | Keyword::HASH) {
+            return Ok(None);
+        }
+        self.next_token();
+        self.expect_token(&Token::LParen)?;
+        let exprs = self.parse_comma_separated(Parser::parse_expr)?;
+        self.expect_token(&Token::RParen)?;
+        Ok(Some(match strategy {
+            Keyword::RANGE => PartitionStrategy::Range(exprs),
+            Keyword::LIST => PartitionStrategy::List(exprs),
+            _ => PartitionStrategy::Hash(exprs),
+        }))
+    }
+
     fn parse_plain_option(&mut self) -> Result<Option<SqlOption>, ParserError> {
         // Single parameter option
         // <https://dev.mysql.com/doc/refman/8.4/en/create-table.html>
@@ -7691,15 +8194,74 @@ impl<'a> Parser<'a> {
         Ok(Some(params))
     }
 
+    /// Parse the `{ INCLUDING | EXCLUDING } { ALL | COMMENTS | ... }` options
+    /// of a PostgreSQL `CREATE TABLE t (LIKE source ...)`.
+    fn parse_create_table_like_options(
+        &mut self,
+    ) -> Result<Vec<CreateTableLikeOption>, ParserError> {
+        let mut options = vec![];
+        while let Some(keyword) =
+            self.parse_one_of_keywords(&[Keyword::INCLUDING, Keyword::EXCLUDING])
+        {
+            let property = match self.expect_one_of_keywords(&[
+                Keyword::ALL,
+                Keyword::COMMENTS,
+                Keyword::COMPRESSION,
+                Keyword::CONSTRAINTS,
+                Keyword::DEFAULTS,
+                Keyword::GENERATED,
+                Keyword::IDENTITY,
+                Keyword::INDEXES,
+                Keyword::STATISTICS,
+                Keyword::STORAGE,
+            ])? {
+                Keyword::ALL => CreateTableLikeProperty::All,
+                Keyword::COMMENTS => CreateTableLikeProperty::Comments,
+                Keyword::COMPRESSION => CreateTableLikeProperty::Compression,
+                Keyword::CONSTRAINTS => CreateTableLikeProperty::Constraints,
+                Keyword::DEFAULTS => CreateTableLikeProperty::Defaults,
+                Keyword::GENERATED => CreateTableLikeProperty::Generated,
+                Keyword::IDENTITY => CreateTableLikeProperty::Identity,
+                Keyword::INDEXES => CreateTableLikeProperty::Indexes,
+                Keyword::STATISTICS => CreateTableLikeProperty::Statistics,
+                Keyword::STORAGE => CreateTableLikeProperty::Storage,
+                _ => unreachable!(),
+            };
+            options.push(match keyword {
+                Keyword::INCLUDING => CreateTableLikeOption::Including(property),
+                _ => CreateTableLikeOption::Excluding(property),
+            });
+        }
+        Ok(options)
+    }
+
     pub fn parse_columns(&mut self) -> Result<(Vec<ColumnDef>, Vec<TableConstraint>), ParserError> {
+        let (columns, constraints, _) = self.parse_columns_inner(false)?;
+        Ok((columns, constraints))
+    }
+
+    /// Parse a column list, additionally accepting a single `PERIOD FOR
+    /// SYSTEM_TIME (start, end)` definition when `allow_period` is set.
+    fn parse_columns_inner(&mut self, allow_period: bool) -> Result<ColumnList, ParserError> {
         let mut columns = vec![];
         let mut constraints = vec![];
+        let mut period = None;
         if !self.consume_token(&Token::LParen) || self.consume_token(&Token::RParen) {
-            return Ok((columns, constraints));
+            return Ok((columns, constraints, period));
         }
 
         loop {
-            if let Some(constraint) = self.parse_optional_table_constraint()? {
+            if allow_period
+                && period.is_none()
+                && self.parse_keywords(&[Keyword::PERIOD, Keyword::FOR, Keyword::SYSTEM_TIME])
+            {
+                self.expect_token(&Token::LParen)?;
+                let start = self.parse_identifier()?;
+                self.expect_token(&Token::Comma)?;
+                let end = self.parse_identifier()?;
+                self.expect_token(&Token::RParen)?;
+                period = Some(PeriodForSystemTime { start, end });
+            } else if let Some(constraint) = self.parse_optional_table_constraint()? {
                 constraints.push(constraint);
             } else if let Token::Word(_) = self.peek_token().token {
                 columns.push(self.parse_column_def()?);
@@ -7724,7 +8286,7 @@ impl<'a> Parser<'a> {
             }
         }
 
-        Ok((columns, constraints))
+        Ok((columns, constraints, period))
     }
 
     pub fn parse_procedure_param(&mut self) -> Result<ProcedureParam, ParserError> {
@@ -7748,7 +8310,9 @@ impl<'a> Parser<'a> {
 
     pub fn parse_column_def(&mut self) -> Result<ColumnDef, ParserError> {
         let name = self.parse_identifier()?;
-        let data_type = if self.is_column_type_sqlite_unspecified() {
+        let data_type = if matches!(self.state, ParserState::PartitionOfColumns)
+            || self.is_column_type_sqlite_unspecified()
+        {
             DataType::Unspecified
         } else {
             self.parse_data_type()?
@@ -8058,6 +8622,20 @@ impl<'a> Parser<'a> {
                 generation_expr_mode: None,
                 generated_keyword: true,
             }))
+        } else if self.parse_keywords(&[Keyword::ALWAYS, Keyword::AS, Keyword::ROW]) {
+            let generated_as = if self.parse_keyword(Keyword::START) {
+                GeneratedAs::RowStart
+            } else {
+                self.expect_keyword_is(Keyword::END)?;
+                GeneratedAs::RowEnd
+            };
+            Ok(Some(ColumnOption::Generated {
+                generated_as,
+                sequence_options: None,
+                generation_expr: None,
+                generation_expr_mode: None,
+                generated_keyword: true,
+            }))
         } else if self.parse_keywords(&[Keyword::ALWAYS, Keyword::AS]) {
             if self.expect_token(&Token::LParen).is_ok() {
                 let expr: Expr = self.with_state(ParserState::Normal, |p| p.parse_expr())?;
@@ -8935,6 +9513,29 @@ impl<'a> Parser<'a> {
         {
             let new_owner = self.parse_owner()?;
             AlterTableOperation::OwnerTo { new_owner }
+        } else if dialect_of!(self is PostgreSqlDialect)
+            && self.parse_keywords(&[Keyword::ATTACH, Keyword::PARTITION])
+        {
+            let partition_name = self.parse_object_name(false)?;
+            let for_values = self.parse_partition_for_values()?;
+            AlterTableOperation::AttachTablePartition {
+                partition_name,
+                for_values,
+            }
+        } else if dialect_of!(self is PostgreSqlDialect)
+            && self.parse_keywords(&[Keyword::DETACH, Keyword::PARTITION])
+        {
+            let partition_name = self.parse_object_name(false)?;
+            let modifier =
+                match self.parse_one_of_keywords(&[Keyword::CONCURRENTLY, Keyword::FINALIZE]) {
+                    Some(Keyword::CONCURRENTLY) => Some(DetachPartitionModifier::Concurrently),
+                    Some(Keyword::FINALIZE) => Some(DetachPartitionModifier::Finalize),
+                    _ => None,
+                };
+            AlterTableOperation::DetachTablePartition {
+                partition_name,
+                modifier,
+            }
         } else if dialect_of!(self is ClickHouseDialect|GenericDialect)
             && self.parse_keyword(Keyword::ATTACH)
         {
@@ -8984,6 +9585,16 @@ impl<'a> Parser<'a> {
             AlterTableOperation::SuspendRecluster
         } else if self.parse_keywords(&[Keyword::RESUME, Keyword::RECLUSTER]) {
             AlterTableOperation::ResumeRecluster
+        } else if dialect_of!(self is SnowflakeDialect | GenericDialect)
+            && self.parse_keywords(&[Keyword::SET, Keyword::TAG])
+        {
+            let tags = self.parse_comma_separated(Parser::parse_tag)?;
+            AlterTableOperation::SetTags { tags }
+        } else if dialect_of!(self is SnowflakeDialect | GenericDialect)
+            && self.parse_keywords(&[Keyword::UNSET, Keyword::TAG])
+        {
+            let keys = self.parse_comma_separated(|p| p.parse_object_name(false))?;
+            AlterTableOperation::UnsetTags { keys }
         } else if self.parse_keyword(Keyword::LOCK) {
             let equals = self.consume_token(&Token::Eq);
             let lock = match self.parse_one_of_keywords(&[
@@ -9102,6 +9713,7 @@ impl<'a> Parser<'a> {
             Keyword::POLICY,
             Keyword::CONNECTOR,
             Keyword::ICEBERG,
+            Keyword::USER,
         ])?;
         match object_type {
             Keyword::VIEW => self.parse_alter_view(),
@@ -9132,6 +9744,7 @@ impl<'a> Parser<'a> {
             Keyword::ROLE => self.parse_alter_role(),
             Keyword::POLICY => self.parse_alter_policy(),
             Keyword::CONNECTOR => self.parse_alter_connector(),
+            Keyword::USER => self.parse_alter_user(),
             // unreachable because expect_one_of_keywords used above
             _ => unreachable!(),
         }
@@ -9255,6 +9868,7 @@ impl<'a> Parser<'a> {
                 args: FunctionArguments::None,
                 over: None,
                 filter: None,
+                from_first: None,
                 null_treatment: None,
                 within_group: vec![],
             }))
@@ -9313,8 +9927,13 @@ impl<'a> Parser<'a> {
             legacy_options.push(opt);
         }
         let values = if let CopyTarget::Stdin = target {
-            self.expect_token(&Token::SemiColon)?;
-            self.parse_tsv()
+            // The inline data, if any, follows the terminating semicolon
+            if self.peek_token_ref().token == Token::EOF {
+                vec![]
+            } else {
+                self.expect_token(&Token::SemiColon)?;
+                self.parse_tsv()
+            }
         } else {
             vec![]
         };
@@ -10005,7 +10624,14 @@ impl<'a> Parser<'a> {
                 Keyword::SET => Ok(DataType::Set(self.parse_string_values()?)),
                 Keyword::ARRAY => {
                     if dialect_of!(self is SnowflakeDialect) {
-                        Ok(DataType::Array(ArrayElemTypeDef::None))
+                        // Snowflake structured arrays: `ARRAY(<element_type>)`
+                        if self.peek_token_ref().token == Token::LParen {
+                            Ok(self.parse_sub_type(|internal_type| {
+                                DataType::Array(ArrayElemTypeDef::Parenthesis(internal_type))
+                            })?)
+                        } else {
+                            Ok(DataType::Array(ArrayElemTypeDef::None))
+                        }
                     } else if dialect_of!(self is ClickHouseDialect) {
                         Ok(self.parse_sub_type(|internal_type| {
                             DataType::Array(ArrayElemTypeDef::Parenthesis(internal_type))
@@ -11882,6 +12508,7 @@ impl<'a> Parser<'a> {
                     into: None,
                     from,
                     lateral_views: vec![],
+                    array_joins: vec![],
                     prewhere: None,
                     selection: None,
                     group_by: GroupByExpr::Expressions(vec![], vec![]),
@@ -11977,6 +12604,21 @@ impl<'a> Parser<'a> {
             }
         }
 
+        let mut array_joins = vec![];
+        if dialect_of!(self is ClickHouseDialect) {
+            loop {
+                let left = if self.parse_keywords(&[Keyword::LEFT, Keyword::ARRAY, Keyword::JOIN]) {
+                    true
+                } else if self.parse_keywords(&[Keyword::ARRAY, Keyword::JOIN]) {
+                    false
+                } else {
+                    break;
+                };
+                let exprs = self.parse_comma_separated(Parser::parse_expr_with_alias)?;
+                array_joins.push(ArrayJoin { left, exprs });
+            }
+        }
+
         let prewhere = if dialect_of!(self is ClickHouseDialect|GenericDialect)
             && self.parse_keyword(Keyword::PREWHERE)
         {
@@ -12064,6 +12706,7 @@ impl<'a> Parser<'a> {
             into,
             from,
             lateral_views,
+            array_joins,
             prewhere,
             selection,
             group_by,
@@ -12125,37 +12768,40 @@ impl<'a> Parser<'a> {
     }
 
     pub fn parse_connect_by(&mut self) -> Result<ConnectBy, ParserError> {
-        let (condition, relationships) = if self.parse_keywords(&[Keyword::CONNECT, Keyword::BY]) {
-            let relationships = self.with_state(ParserState::ConnectBy, |parser| {
-                parser.parse_comma_separated(Parser::parse_expr)
-            })?;
-            self.expect_keywords(&[Keyword::START, Keyword::WITH])?;
-            let condition = self.parse_expr()?;
-            (condition, relationships)
-        } else {
-            self.expect_keywords(&[Keyword::START, Keyword::WITH])?;
-            let condition = self.parse_expr()?;
-            self.expect_keywords(&[Keyword::CONNECT, Keyword::BY])?;
-            let relationships = self.with_state(ParserState::ConnectBy, |parser| {
-                parser.parse_comma_separated(Parser::parse_expr)
-            })?;
-            (condition, relationships)
-        };
+        let (condition, relationships, nocycle) =
+            if self.parse_keywords(&[Keyword::CONNECT, Keyword::BY]) {
+                let nocycle = self.parse_keyword(Keyword::NOCYCLE);
+                let relationships = self.with_state(ParserState::ConnectBy, |parser| {
+                    parser.parse_comma_separated(Parser::parse_expr)
+                })?;
+                self.expect_keywords(&[Keyword::START, Keyword::WITH])?;
+                let condition = self.parse_expr()?;
+                (condition, relationships, nocycle)
+            } else {
+                self.expect_keywords(&[Keyword::START, Keyword::WITH])?;
+                let condition = self.parse_expr()?;
+                self.expect_keywords(&[Keyword::CONNECT, Keyword::BY])?;
+                let nocycle = self.parse_keyword(Keyword::NOCYCLE);
+                let relationships = self.with_state(ParserState::ConnectBy, |parser| {
+                    parser.parse_comma_separated(Parser::parse_expr)
+                })?;
+                (condition, relationships, nocycle)
+            };
         Ok(ConnectBy {
             condition,
             relationships,
+            nocycle,
         })
     }
 
     /// Parse `CREATE TABLE x AS TABLE y`
     pub fn parse_as_table(&mut self) -> Result<Table, ParserError> {
         let token1 = self.next_token();
-        let token2 = self.next_token();
-        let token3 = self.next_token();
 
         let table_name;
         let schema_name;
-        if token2 == Token::Period {
+        if self.consume_token(&Token::Period) {
+            let token3 = self.next_token();
             match token1.token {
                 Token::Word(w) => {
                     schema_name = w.value;
@@ -12210,6 +12856,35 @@ impl<'a> Parser<'a> {
         }))
     }
 
+    fn parse_set_session_authorization(
+        &mut self,
+        modifier: Option<ContextModifier>,
+    ) -> Result<Statement, ParserError> {
+        let user_name = if self.parse_keyword(Keyword::DEFAULT) {
+            None
+        } else {
+            Some(self.parse_identifier()?)
+        };
+        Ok(Statement::Set(Set::SetSessionAuthorization {
+            context_modifier: modifier,
+            user_name,
+        }))
+    }
+
+    /// Parse a `RESET` statement, assuming the `RESET` keyword was already consumed
+    pub fn parse_reset(&mut self) -> Result<Statement, ParserError> {
+        let reset = if self.parse_keyword(Keyword::ALL) {
+            Reset::All
+        } else if self.parse_keyword(Keyword::ROLE) {
+            Reset::Role
+        } else if self.parse_keywords(&[Keyword::SESSION, Keyword::AUTHORIZATION]) {
+            Reset::SessionAuthorization
+        } else {
+            Reset::ConfigurationParameter(self.parse_object_name(false)?)
+        };
+        Ok(Statement::Reset(reset))
+    }
+
     fn parse_set_values(
         &mut self,
         parenthesized_assignment: bool,
@@ -12290,6 +12965,13 @@ impl<'a> Parser<'a> {
             return Ok(set_role_stmt);
         }
 
+        // `SET SESSION AUTHORIZATION` is not scoped, unlike `SET SESSION SESSION AUTHORIZATION`
+        if scope == Some(ContextModifier::Session) && self.parse_keyword(Keyword::AUTHORIZATION) {
+            return self.parse_set_session_authorization(None);
+        } else if self.parse_keywords(&[Keyword::SESSION, Keyword::AUTHORIZATION]) {
+            return self.parse_set_session_authorization(scope);
+        }
+
         // Handle special cases first
         if self.parse_keywords(&[Keyword::TIME, Keyword::ZONE])
             || self.parse_keyword(Keyword::TIMEZONE)
@@ -12515,6 +13197,13 @@ impl<'a> Parser<'a> {
             Ok(self.parse_show_views(terse, false)?)
         } else if self.parse_keyword(Keyword::FUNCTIONS) {
             Ok(self.parse_show_functions()?)
+        } else if !full
+            && dialect_of!(self is MySqlDialect | GenericDialect)
+            && self
+                .peek_one_of_keywords(&[Keyword::INDEX, Keyword::INDEXES, Keyword::KEYS])
+                .is_some()
+        {
+            Ok(self.parse_show_index(extended)?)
         } else if extended || full {
             Err(ParserError::ParserError(
                 "EXTENDED/FULL are not supported with this type of SHOW query".to_string(),
@@ -12608,6 +13297,25 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// Parse `{INDEX | INDEXES | KEYS} {FROM | IN} tbl_name ...` after `SHOW [EXTENDED]`
+    pub fn parse_show_index(&mut self, extended: bool) -> Result<Statement, ParserError> {
+        let keyword = match self.expect_one_of_keywords(&[
+            Keyword::INDEX,
+            Keyword::INDEXES,
+            Keyword::KEYS,
+        ])? {
+            Keyword::INDEXES => ShowIndexKeyword::Indexes,
+            Keyword::KEYS => ShowIndexKeyword::Keys,
+            _ => ShowIndexKeyword::Index,
+        };
+        let show_options = self.parse_show_stmt_options()?;
+        Ok(Statement::ShowIndex {
+            extended,
+            keyword,
+            show_options,
+        })
+    }
+
     fn parse_show_tables(
         &mut self,
         terse: bool,
@@ -12736,6 +13444,16 @@ impl<'a> Parser<'a> {
     fn parse_joins(&mut self) -> Result<Vec<Join>, ParserError> {
         let mut joins = vec![];
         loop {
+            // ClickHouse `LEFT ARRAY JOIN` is parsed as part of the `SELECT`
+            if dialect_of!(self is ClickHouseDialect)
+                && matches!(
+                    self.peek_tokens(),
+                    [Token::Word(left), Token::Word(array)]
+                        if left.keyword == Keyword::LEFT && array.keyword == Keyword::ARRAY
+                )
+            {
+                break;
+            }
             let global = self.parse_keyword(Keyword::GLOBAL);
             let join = if self.parse_keyword(Keyword::CROSS) {
                 let join_operator = if self.parse_keyword(Keyword::JOIN) {
@@ -13135,7 +13853,13 @@ impl<'a> Parser<'a> {
             self.prev_token();
             self.parse_xml_table_factor()
         } else {
-            let name = self.parse_object_name(true)?;
+            let name = if self.dialect.supports_select_from_stage()
+                && self.peek_token_ref().token == Token::AtSign
+            {
+                parse_snowflake_stage_name(self)?
+            } else {
+                self.parse_object_name(true)?
+            };
 
             let json_path = match self.peek_token().token {
                 Token::LBracket if self.dialect.supports_partiql() => Some(self.parse_json_path()?),
@@ -13191,7 +13915,7 @@ impl<'a> Parser<'a> {
                 }
             };
 
-            if !self.dialect.supports_table_sample_before_alias() {
+            if sample.is_none() && self.dialect.supports_table_sample_after_alias() {
                 if let Some(parsed_sample) = self.maybe_parse_table_sample()? {
                     sample = Some(TableSampleKind::AfterTableAlias(parsed_sample));
                 }
@@ -13694,10 +14418,8 @@ impl<'a> Parser<'a> {
     /// Parses a the timestamp version specifier (i.e. query historical data)
     pub fn maybe_parse_table_version(&mut self) -> Result<Option<TableVersion>, ParserError> {
         if self.dialect.supports_timestamp_versioning() {
-            if self.parse_keywords(&[Keyword::FOR, Keyword::SYSTEM_TIME, Keyword::AS, Keyword::OF])
-            {
-                let expr = self.parse_expr()?;
-                return Ok(Some(TableVersion::ForSystemTimeAsOf(expr)));
+            if self.parse_keywords(&[Keyword::FOR, Keyword::SYSTEM_TIME]) {
+                return self.parse_for_system_time().map(Some);
             } else if self.peek_keyword(Keyword::AT) || self.peek_keyword(Keyword::BEFORE) {
                 let func_name = self.parse_object_name(true)?;
                 let func = self.parse_function(func_name)?;
@@ -13707,6 +14429,38 @@ impl<'a> Parser<'a> {
         Ok(None)
     }
 
+    /// Parses the period of a temporal table query, after `FOR SYSTEM_TIME`.
+    fn parse_for_system_time(&mut self) -> Result<TableVersion, ParserError> {
+        if self.parse_keywords(&[Keyword::AS, Keyword::OF]) {
+            Ok(TableVersion::ForSystemTimeAsOf(self.parse_expr()?))
+        } else if self.parse_keyword(Keyword::BETWEEN) {
+            // Stop before the `AND` separating the bounds
+            let low = self.parse_subexpr(self.dialect.prec_value(Precedence::Between))?;
+            self.expect_keyword_is(Keyword::AND)?;
+            let high = self.parse_subexpr(self.dialect.prec_value(Precedence::Between))?;
+            Ok(TableVersion::ForSystemTimeBetween { low, high })
+        } else if self.parse_keyword(Keyword::FROM) {
+            let from = self.parse_expr()?;
+            self.expect_keyword_is(Keyword::TO)?;
+            let to = self.parse_expr()?;
+            Ok(TableVersion::ForSystemTimeFromTo { from, to })
+        } else if self.parse_keywords(&[Keyword::CONTAINED, Keyword::IN]) {
+            self.expect_token(&Token::LParen)?;
+            let from = self.parse_expr()?;
+            self.expect_token(&Token::Comma)?;
+            let to = self.parse_expr()?;
+            self.expect_token(&Token::RParen)?;
+            Ok(TableVersion::ForSystemTimeContainedIn { from, to })
+        } else if self.parse_keyword(Keyword::ALL) {
+            Ok(TableVersion::ForSystemTimeAll)
+        } else {
+            self.expected(
+                "AS OF, BETWEEN, FROM, CONTAINED IN or ALL after FOR SYSTEM_TIME",
+                self.peek_token(),
+            )
+        }
+    }
+
     /// Parses MySQL's JSON_TABLE column definition.
     /// For example: `id INT EXISTS PATH '$' DEFAULT '0' ON EMPTY ERROR ON ERROR`
     pub fn parse_json_table_column_def(&mut self) -> Result<JsonTableColumn, ParserError> {
@@ -13961,6 +14715,8 @@ impl<'a> Parser<'a> {
 
         let with_grant_option =
             self.parse_keywords(&[Keyword::WITH, Keyword::GRANT, Keyword::OPTION]);
+        let with_admin_option =
+            self.parse_keywords(&[Keyword::WITH, Keyword::ADMIN, Keyword::OPTION]);
 
         let current_grants =
             if self.parse_keywords(&[Keyword::COPY, Keyword::CURRENT, Keyword::GRANTS]) {
@@ -13988,6 +14744,7 @@ impl<'a> Parser<'a> {
             objects,
             grantees,
             with_grant_option,
+            with_admin_option,
             as_grantor,
             granted_by,
             current_grants,
@@ -14069,6 +14826,31 @@ impl<'a> Parser<'a> {
             Privileges::All {
                 with_privileges_keyword: self.parse_keyword(Keyword::PRIVILEGES),
             }
+        } else if dialect_of!(self is PostgreSqlDialect | GenericDialect) {
+            // Privileges are always granted `ON <objects>`, anything else is a
+            // PostgreSQL role membership, e.g. `GRANT role1, role2 TO user`
+            if let Some(actions) = self.maybe_parse(|parser| {
+                let actions = parser.parse_actions_list()?;
+                parser.expect_keyword_is(Keyword::ON)?;
+                parser.prev_token();
+                Ok(actions)
+            })? {
+                Privileges::Actions(actions)
+            } else if let Some(roles) = self.maybe_parse(|parser| {
+                let roles = parser.parse_comma_separated(|p| p.parse_object_name(false))?;
+                if parser
+                    .peek_one_of_keywords(&[Keyword::TO, Keyword::FROM])
+                    .is_none()
+                {
+                    return parser.expected("TO or FROM", parser.peek_token());
+                }
+                Ok(roles)
+            })? {
+                Privileges::Roles(roles)
+            } else {
+                // report the error for the privileges
+                Privileges::Actions(self.parse_actions_list()?)
+            }
         } else {
             let actions = self.parse_actions_list()?;
             Privileges::Actions(actions)
@@ -14936,7 +15718,13 @@ impl<'a> Parser<'a> {
     pub fn parse_function_args(&mut self) -> Result<FunctionArg, ParserError> {
         let arg = if self.dialect.supports_named_fn_args_with_expr_name() {
             self.maybe_parse(|p| {
-                let name = p.parse_expr()?;
+                let name = if p.dialect.supports_named_fn_args_with_assignment_operator() {
+                    // Stop before `:=`, which would otherwise be parsed as
+                    // part of an assignment expression
+                    p.parse_subexpr(p.dialect.prec_value(Precedence::Eq))?
+                } else {
+                    p.parse_expr()?
+                };
                 let operator = p.parse_function_named_arg_operator()?;
                 let arg = p.parse_wildcard_expr()?.into();
                 Ok(FunctionArg::ExprNamed {
@@ -16085,6 +16873,11 @@ impl<'a> Parser<'a> {
         } else {
             None
         };
+        let returning = if self.parse_keyword(Keyword::RETURNING) {
+            Some(self.parse_comma_separated(Parser::parse_select_item)?)
+        } else {
+            None
+        };
 
         Ok(Statement::Merge {
             into,
@@ -16093,14 +16886,37 @@ impl<'a> Parser<'a> {
             on: Box::new(on),
             clauses,
             output,
+            returning,
         })
     }
 
     fn parse_pragma_value(&mut self) -> Result<Value, ParserError> {
+        // unquoted keywords and names such as `ON`, `WAL` or a table name are
+        // kept verbatim, the same as unquoted `TABLESAMPLE` quantities
+        if let Token::Word(w) = &self.peek_token_ref().token {
+            if w.quote_style.is_none()
+                && !matches!(w.keyword, Keyword::TRUE | Keyword::FALSE | Keyword::NULL)
+            {
+                return Ok(Value::Placeholder(self.parse_identifier()?.value));
+            }
+        }
+        if self.consume_token(&Token::Minus) {
+            return match self.parse_number_value()?.value {
+                #[cfg(not(feature = "bigdecimal"))]
+                Value::Number(n, l) => Ok(Value::Number(format!("-{n}"), l)),
+                #[cfg(feature = "bigdecimal")]
+                Value::Number(n, l) => Ok(Value::Number(-n, l)),
+                _ => {
+                    self.prev_token();
+                    self.expected("number after -", self.peek_token())
+                }
+            };
+        }
         match self.parse_value()?.value {
             v @ Value::SingleQuotedString(_) => Ok(v),
             v @ Value::DoubleQuotedString(_) => Ok(v),
             v @ Value::Number(_, _) => Ok(v),
+            v @ Value::Boolean(_) => Ok(v),
             v @ Value::Placeholder(_) => Ok(v),
             _ => {
                 self.prev_token();
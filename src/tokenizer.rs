@@ -1209,10 +1209,14 @@ impl<'a> Tokenizer<'a> {
 
                     // Some dialects support underscore as number separator
                     // There can only be one at a time and it must be followed by another digit
+                    let is_separator_followed_by =
+                        |ch: char, next_char: Option<char>, is_digit: fn(&char) -> bool| {
+                            self.dialect.supports_numeric_literal_underscores()
+                                && ch == '_'
+                                && next_char.as_ref().is_some_and(is_digit)
+                        };
                     let is_number_separator = |ch: char, next_char: Option<char>| {
-                        self.dialect.supports_numeric_literal_underscores()
-                            && ch == '_'
-                            && next_char.is_some_and(|next_ch| next_ch.is_ascii_hexdigit())
+                        is_separator_followed_by(ch, next_char, char::is_ascii_digit)
                     };
 
                     let mut s = peeking_next_take_while(chars, |ch, next_ch| {
@@ -1223,7 +1227,8 @@ impl<'a> Tokenizer<'a> {
                     if s == "0" && chars.peek() == Some(&'x') {
                         chars.next();
                         let s2 = peeking_next_take_while(chars, |ch, next_ch| {
-                            ch.is_ascii_hexdigit() || is_number_separator(ch, next_ch)
+                            ch.is_ascii_hexdigit()
+                                || is_separator_followed_by(ch, next_ch, char::is_ascii_hexdigit)
                         });
                         return Ok(Some(Token::HexStringLiteral(s2)));
                     }
@@ -1276,8 +1281,9 @@ impl<'a> Tokenizer<'a> {
                                 for _ in 0..exponent_part.len() {
                                     chars.next();
                                 }
-                                exponent_part +=
-                                    &peeking_take_while(chars, |ch| ch.is_ascii_digit());
+                                exponent_part += &peeking_next_take_while(chars, |ch, next_ch| {
+                                    ch.is_ascii_digit() || is_number_separator(ch, next_ch)
+                                });
                                 s += exponent_part.as_str();
                             }
                             // Not an exponent, discard the work done
@@ -2521,6 +2527,25 @@ mod tests {
                 Token::make_word("___0", None), // multiple underscores tokenizes as a word (syntax error in some dialects)
             ],
         );
+
+        all_dialects_where(|dialect| dialect.supports_numeric_literal_underscores()).tokenizes_to(
+            "SELECT 1_000.000_1, 1_0e1_0, 0xf_f, 1_a",
+            vec![
+                Token::make_keyword("SELECT"),
+                Token::Whitespace(Whitespace::Space),
+                Token::Number("1_000.000_1".to_string(), false),
+                Token::Comma,
+                Token::Whitespace(Whitespace::Space),
+                Token::Number("1_0e1_0".to_string(), false),
+                Token::Comma,
+                Token::Whitespace(Whitespace::Space),
+                Token::HexStringLiteral("f_f".to_string()),
+                Token::Comma,
+                Token::Whitespace(Whitespace::Space),
+                Token::Number("1".to_string(), false),
+                Token::make_word("_a", None), // the separator must be followed by a digit
+            ],
+        );
     }
 
     #[test]
@@ -2166,6 +2166,7 @@ fn parse_map_access_expr() {
                         clauses: vec![],
                     }),
                     filter: None,
+                    from_first: None,
                     null_treatment: None,
                     over: None,
                     within_group: vec![],
@@ -2566,3 +2567,100 @@ fn test_struct_trailing_and_nested_bracket() {
         )
     );
 }
+
+#[test]
+fn test_parenthesized_struct_field_access() {
+    let select = bigquery()
+        .verified_only_select("SELECT (f()).field.subfield, struct_col.field.subfield FROM t");
+    assert_eq!(
+        &Expr::CompoundFieldAccess {
+            root: Box::new(Expr::Nested(Box::new(call("f", [])))),
+            access_chain: vec![
+                AccessExpr::Dot(Expr::Identifier(Ident::new("field"))),
+                AccessExpr::Dot(Expr::Identifier(Ident::new("subfield"))),
+            ],
+        },
+        expr_from_projection(&select.projection[0])
+    );
+    assert_eq!(
+        &Expr::CompoundIdentifier(vec![
+            Ident::new("struct_col"),
+            Ident::new("field"),
+            Ident::new("subfield"),
+        ]),
+        expr_from_projection(&select.projection[1])
+    );
+
+    bigquery().verified_stmt("SELECT (STRUCT(1 AS a, 2 AS b)).a");
+    bigquery().verified_stmt("SELECT (t.arr[OFFSET(0)]).x FROM t");
+}
+
+#[test]
+fn test_window_function_null_treatment_placement() {
+    let select = bigquery()
+        .verified_only_select("SELECT LAST_VALUE(x) IGNORE NULLS OVER (ORDER BY y) FROM t");
+    match expr_from_projection(only(&select.projection)) {
+        Expr::Function(f) => assert_eq!(f.null_treatment, Some(NullTreatment::IgnoreNulls)),
+        _ => unreachable!(),
+    }
+
+    // inside the parentheses the null treatment is kept as an argument clause
+    let select = bigquery()
+        .verified_only_select("SELECT LAST_VALUE(x RESPECT NULLS) OVER (ORDER BY y) FROM t");
+    match expr_from_projection(only(&select.projection)) {
+        Expr::Function(Function {
+            args: FunctionArguments::List(FunctionArgumentList { clauses, .. }),
+            null_treatment,
+            ..
+        }) => {
+            assert_eq!(*null_treatment, None);
+            assert_eq!(
+                clauses,
+                &vec![FunctionArgumentClause::IgnoreOrRespectNulls(
+                    NullTreatment::RespectNulls
+                )]
+            );
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn test_select_wildcard_with_except_and_replace() {
+    let select =
+        bigquery().verified_only_select("SELECT * EXCEPT (a, b) REPLACE (x + 1 AS y) FROM t");
+    match only(&select.projection) {
+        SelectItem::Wildcard(WildcardAdditionalOptions {
+            opt_exclude,
+            opt_except,
+            opt_replace,
+            ..
+        }) => {
+            assert_eq!(*opt_exclude, None);
+            assert_eq!(
+                *opt_except,
+                Some(ExceptSelectItem {
+                    first_element: Ident::new("a"),
+                    additional_elements: vec![Ident::new("b")],
+                })
+            );
+            assert_eq!(
+                *opt_replace,
+                Some(ReplaceSelectItem {
+                    items: vec![Box::new(ReplaceSelectElement {
+                        expr: Expr::BinaryOp {
+                            left: Box::new(Expr::Identifier(Ident::new("x"))),
+                            op: BinaryOperator::Plus,
+                            right: Box::new(Expr::value(number("1"))),
+                        },
+                        column_name: Ident::new("y"),
+                        as_keyword: true,
+                    })],
+                })
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    bigquery().verified_only_select("SELECT t.* EXCEPT (a) FROM t");
+}
@@ -67,6 +67,7 @@ fn parse_map_access_expr() {
                 joins: vec![],
             }],
             lateral_views: vec![],
+            array_joins: vec![],
             prewhere: None,
             selection: Some(BinaryOp {
                 left: Box::new(BinaryOp {
@@ -198,6 +199,7 @@ fn parse_delimited_identifiers() {
                 args: vec![],
                 clauses: vec![],
             }),
+            from_first: None,
             null_treatment: None,
             filter: None,
             over: None,
@@ -835,6 +837,7 @@ fn parse_create_table_with_variant_default_expressions() {
                                     clauses: vec![],
                                 }),
                                 parameters: FunctionArguments::None,
+                                from_first: None,
                                 null_treatment: None,
                                 filter: None,
                                 over: None,
@@ -856,6 +859,7 @@ fn parse_create_table_with_variant_default_expressions() {
                                     clauses: vec![],
                                 }),
                                 parameters: FunctionArguments::None,
+                                from_first: None,
                                 null_treatment: None,
                                 filter: None,
                                 over: None,
@@ -887,6 +891,7 @@ fn parse_create_table_with_variant_default_expressions() {
                                     clauses: vec![],
                                 }),
                                 parameters: FunctionArguments::None,
+                                from_first: None,
                                 null_treatment: None,
                                 filter: None,
                                 over: None,
@@ -1398,6 +1403,54 @@ fn test_prewhere() {
         }
         _ => unreachable!(),
     }
+
+    // PREWHERE sits between FROM and WHERE, and is followed by the remaining clauses
+    clickhouse_and_generic().verified_stmt(
+        "SELECT c, count() FROM t PREWHERE a IN (SELECT id FROM u) WHERE b > 0 GROUP BY c ORDER BY c",
+    );
+    assert!(clickhouse()
+        .parse_sql_statements("SELECT * FROM t WHERE y = 2 PREWHERE x = 1")
+        .is_err());
+}
+
+#[test]
+fn test_array_join() {
+    let select = clickhouse().verified_only_select("SELECT s, a FROM t ARRAY JOIN arr AS a");
+    assert_eq!(
+        select.from[0].relation,
+        table_from_name(ObjectName::from(vec![Ident::new("t")]))
+    );
+    assert_eq!(
+        select.array_joins,
+        vec![ArrayJoin {
+            left: false,
+            exprs: vec![ExprWithAlias {
+                expr: Identifier(Ident::new("arr")),
+                alias: Some(Ident::new("a")),
+            }],
+        }]
+    );
+
+    let select = clickhouse()
+        .verified_only_select("SELECT s FROM t LEFT ARRAY JOIN arr AS a, [1, 2] AS b WHERE a > 1");
+    assert!(select.from[0].joins.is_empty());
+    assert_eq!(select.array_joins.len(), 1);
+    assert!(select.array_joins[0].left);
+    assert_eq!(
+        select.array_joins[0]
+            .exprs
+            .iter()
+            .map(|e| e.alias.clone())
+            .collect::<Vec<_>>(),
+        vec![Some(Ident::new("a")), Some(Ident::new("b"))]
+    );
+    assert!(select.selection.is_some());
+
+    clickhouse().verified_stmt("SELECT s, arr FROM t ARRAY JOIN arr");
+    clickhouse().verified_stmt(
+        "SELECT * FROM t JOIN u ON t.id = u.id ARRAY JOIN arr LEFT ARRAY JOIN nested.x AS x",
+    );
+    clickhouse().verified_stmt("SELECT * FROM t AS array");
 }
 
 #[test]
@@ -1453,6 +1506,29 @@ fn test_query_with_format_clause() {
         }
     }
 
+    clickhouse()
+        .one_statement_parses_to("SELECT * FROM t FORMAT Null", "SELECT * FROM t FORMAT NULL");
+    match clickhouse().verified_stmt("SELECT a, b FROM t WHERE a = 1 FORMAT JSONEachRow") {
+        Statement::Query(query) => {
+            assert_eq!(
+                query.format_clause,
+                Some(FormatClause::Identifier(Ident::new("JSONEachRow")))
+            );
+        }
+        _ => unreachable!(),
+    }
+    // `FORMAT` must not be taken as an alias of the last select item
+    match clickhouse().verified_stmt("SELECT 1 FORMAT TabSeparated") {
+        Statement::Query(query) => {
+            assert_eq!(
+                query.format_clause,
+                Some(FormatClause::Identifier(Ident::new("TabSeparated")))
+            );
+        }
+        _ => unreachable!(),
+    }
+    clickhouse().verified_stmt("SELECT 1 SETTINGS max_threads = 1 FORMAT Pretty");
+
     let invalid_cases = [
         "SELECT * FROM t FORMAT",
         "SELECT * FROM t FORMAT TabSeparated JSONCompact",
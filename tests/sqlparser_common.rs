@@ -40,7 +40,7 @@ use sqlparser::dialect::{
 use sqlparser::keywords::{Keyword, ALL_KEYWORDS};
 use sqlparser::parser::{Parser, ParserError, ParserOptions};
 use sqlparser::tokenizer::Tokenizer;
-use sqlparser::tokenizer::{Location, Span};
+use sqlparser::tokenizer::{Location, Span, Token};
 use test_utils::{
     all_dialects, all_dialects_where, all_dialects_with_options, alter_table_op, assert_eq_vec,
     call, expr_from_projection, join, number, only, table, table_alias, table_from_name,
@@ -177,6 +177,32 @@ fn parse_replace_into() {
     )
 }
 
+#[test]
+fn parse_error_includes_location() {
+    let dialect = GenericDialect {};
+
+    assert_eq!(
+        ParserError::ParserError("Expected: FOR, found: SELECT at Line: 1, Column: 18".to_string()),
+        Parser::parse_sql(&dialect, "DECLARE c CURSOR SELECT 1").unwrap_err(),
+    );
+    assert_eq!(
+        "sql parser error: Expected: an expression, found: ) at Line: 2, Column: 7",
+        Parser::parse_sql(&dialect, "SELECT a FROM t WHERE\n  b = )")
+            .unwrap_err()
+            .to_string(),
+    );
+
+    // Tokens without location information keep the plain message
+    let tokens = vec![Token::make_keyword("SELECT"), Token::RParen];
+    assert_eq!(
+        ParserError::ParserError("Expected: an expression, found: )".to_string()),
+        Parser::new(&dialect)
+            .with_tokens(tokens)
+            .parse_statements()
+            .unwrap_err(),
+    );
+}
+
 #[test]
 fn parse_insert_default_values() {
     let insert_with_default_values = verified_stmt("INSERT INTO test_table DEFAULT VALUES");
@@ -469,6 +495,7 @@ fn parse_update_set_from() {
                                 joins: vec![],
                             }],
                             lateral_views: vec![],
+                            array_joins: vec![],
                             prewhere: None,
                             selection: None,
                             group_by: GroupByExpr::Expressions(
@@ -1290,6 +1317,7 @@ fn parse_select_count_wildcard() {
                 args: vec![FunctionArg::Unnamed(FunctionArgExpr::Wildcard)],
                 clauses: vec![],
             }),
+            from_first: None,
             null_treatment: None,
             filter: None,
             over: None,
@@ -1316,6 +1344,7 @@ fn parse_select_count_distinct() {
                 }))],
                 clauses: vec![],
             }),
+            from_first: None,
             null_treatment: None,
             within_group: vec![],
             filter: None,
@@ -2364,6 +2393,19 @@ fn parse_binary_any() {
         }),
         select.projection[0]
     );
+
+    let select = verified_only_select("SELECT a <> ANY(b)");
+    assert_eq!(
+        SelectItem::UnnamedExpr(Expr::AnyOp {
+            left: Box::new(Expr::Identifier(Ident::new("a"))),
+            compare_op: BinaryOperator::NotEq,
+            right: Box::new(Expr::Identifier(Ident::new("b"))),
+            is_some: false,
+        }),
+        select.projection[0]
+    );
+
+    verified_stmt("SELECT * FROM t WHERE a <> ANY(SELECT b FROM u)");
 }
 
 #[test]
@@ -2377,6 +2419,18 @@ fn parse_binary_all() {
         }),
         select.projection[0]
     );
+
+    let select = verified_only_select("SELECT a >= ALL(b)");
+    assert_eq!(
+        SelectItem::UnnamedExpr(Expr::AllOp {
+            left: Box::new(Expr::Identifier(Ident::new("a"))),
+            compare_op: BinaryOperator::GtEq,
+            right: Box::new(Expr::Identifier(Ident::new("b"))),
+        }),
+        select.projection[0]
+    );
+
+    verified_stmt("SELECT * FROM t WHERE a >= ALL(SELECT b FROM u)");
 }
 
 #[test]
@@ -2529,6 +2583,30 @@ fn parse_select_order_by() {
     chk("SELECT 1 AS lname, 2 AS fname, 3 AS id, 4 ORDER BY lname ASC, fname DESC, id");
 }
 
+#[test]
+fn parse_select_order_by_positional_and_named() {
+    let select =
+        verified_query("SELECT id, name, created_at FROM t ORDER BY 1 DESC, name ASC, created_at");
+    let order_by_expr = |expr: Expr, asc: Option<bool>| OrderByExpr {
+        expr,
+        options: OrderByOptions {
+            asc,
+            nulls_first: None,
+        },
+        with_fill: None,
+    };
+    assert_eq!(
+        OrderByKind::Expressions(vec![
+            order_by_expr(Expr::value(number("1")), Some(false)),
+            order_by_expr(Expr::Identifier(Ident::new("name")), Some(true)),
+            order_by_expr(Expr::Identifier(Ident::new("created_at")), None),
+        ]),
+        select.order_by.expect("ORDER BY expected").kind
+    );
+
+    verified_query("SELECT id, name FROM t ORDER BY 2, 1 ASC");
+}
+
 #[test]
 fn parse_select_order_by_limit() {
     let sql = "SELECT id, fname, lname FROM customer WHERE id < 5 \
@@ -2896,6 +2974,7 @@ fn parse_select_having() {
                     args: vec![FunctionArg::Unnamed(FunctionArgExpr::Wildcard)],
                     clauses: vec![],
                 }),
+                from_first: None,
                 null_treatment: None,
                 filter: None,
                 over: None,
@@ -2927,6 +3006,7 @@ fn parse_select_qualify() {
                     args: vec![],
                     clauses: vec![],
                 }),
+                from_first: None,
                 null_treatment: None,
                 filter: None,
                 over: Some(WindowType::WindowSpec(WindowSpec {
@@ -3359,6 +3439,7 @@ fn parse_listagg() {
                 )],
             }),
             filter: None,
+            from_first: None,
             null_treatment: None,
             over: None,
             within_group: vec![
@@ -5335,6 +5416,31 @@ fn parse_explain_analyze_with_simple_select() {
     );
 }
 
+#[test]
+fn parse_explain_analyze_dml() {
+    match verified_stmt("EXPLAIN ANALYZE DELETE FROM t WHERE x") {
+        Statement::Explain {
+            analyze, statement, ..
+        } => {
+            assert!(analyze);
+            assert!(matches!(*statement, Statement::Delete(_)));
+        }
+        _ => unreachable!(),
+    }
+    match verified_stmt("EXPLAIN ANALYZE UPDATE t SET a = 1 WHERE b = 2") {
+        Statement::Explain { statement, .. } => {
+            assert!(matches!(*statement, Statement::Update { .. }));
+        }
+        _ => unreachable!(),
+    }
+    match verified_stmt("EXPLAIN ANALYZE INSERT INTO t (a) VALUES (1)") {
+        Statement::Explain { statement, .. } => {
+            assert!(matches!(*statement, Statement::Insert(_)));
+        }
+        _ => unreachable!(),
+    }
+}
+
 #[test]
 fn parse_explain_query_plan() {
     match all_dialects().verified_stmt("EXPLAIN QUERY PLAN SELECT sqrt(id) FROM foo") {
@@ -5427,6 +5533,7 @@ fn parse_named_argument_function() {
                 ],
                 clauses: vec![],
             }),
+            from_first: None,
             null_treatment: None,
             filter: None,
             over: None,
@@ -5467,6 +5574,7 @@ fn parse_named_argument_function_with_eq_operator() {
                 ],
                 clauses: vec![],
             }),
+            from_first: None,
             null_treatment: None,
             filter: None,
             over: None,
@@ -5527,6 +5635,7 @@ fn parse_window_functions() {
                 args: vec![],
                 clauses: vec![],
             }),
+            from_first: None,
             null_treatment: None,
             filter: None,
             over: Some(WindowType::WindowSpec(WindowSpec {
@@ -5561,6 +5670,48 @@ fn parse_window_functions() {
     }
 }
 
+#[test]
+fn parse_window_frame_exclusion() {
+    let sql = "SELECT sum(x) OVER (ORDER BY x GROUPS BETWEEN 1 PRECEDING AND 1 FOLLOWING EXCLUDE TIES) FROM t";
+    let select = all_dialects().verified_only_select(sql);
+    match expr_from_projection(only(&select.projection)) {
+        Expr::Function(Function {
+            over: Some(WindowType::WindowSpec(WindowSpec { window_frame, .. })),
+            ..
+        }) => assert_eq!(
+            window_frame,
+            &Some(WindowFrame {
+                units: WindowFrameUnits::Groups,
+                start_bound: WindowFrameBound::Preceding(Some(Box::new(Expr::value(number("1"))))),
+                end_bound: Some(WindowFrameBound::Following(Some(Box::new(Expr::value(
+                    number("1")
+                ))))),
+                exclusion: Some(WindowFrameExclusion::Ties),
+            })
+        ),
+        _ => unreachable!(),
+    }
+
+    all_dialects().verified_stmt(
+        "SELECT sum(x) OVER (ORDER BY x ROWS UNBOUNDED PRECEDING EXCLUDE CURRENT ROW) FROM t",
+    );
+    all_dialects().verified_stmt(
+        "SELECT sum(x) OVER (ORDER BY x GROUPS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW EXCLUDE GROUP) FROM t",
+    );
+    all_dialects().verified_stmt(
+        "SELECT sum(x) OVER (ORDER BY x RANGE BETWEEN 1 PRECEDING AND CURRENT ROW EXCLUDE NO OTHERS) FROM t",
+    );
+
+    assert_eq!(
+        all_dialects()
+            .parse_sql_statements("SELECT sum(x) OVER (ORDER BY x ROWS 1 PRECEDING EXCLUDE) FROM t")
+            .unwrap_err(),
+        ParserError::ParserError(
+            "Expected: CURRENT ROW, GROUP, TIES or NO OTHERS after EXCLUDE, found: )".to_string()
+        )
+    );
+}
+
 #[test]
 fn parse_named_window_functions() {
     let supported_dialects = TestedDialects::new(vec![
@@ -5673,6 +5824,7 @@ fn test_parse_named_window() {
                         ))],
                         clauses: vec![],
                     }),
+                    from_first: None,
                     null_treatment: None,
                     filter: None,
                     over: Some(WindowType::NamedWindow(Ident {
@@ -5708,6 +5860,7 @@ fn test_parse_named_window() {
                         ))],
                         clauses: vec![],
                     }),
+                    from_first: None,
                     null_treatment: None,
                     filter: None,
                     over: Some(WindowType::NamedWindow(Ident {
@@ -5735,6 +5888,7 @@ fn test_parse_named_window() {
             joins: vec![],
         }],
         lateral_views: vec![],
+        array_joins: vec![],
         prewhere: None,
         selection: None,
         group_by: GroupByExpr::Expressions(vec![], vec![]),
@@ -5991,6 +6145,33 @@ fn parse_literal_timestamp_with_time_zone() {
     );
 
     one_statement_parses_to("SELECT TIMESTAMPTZ '1999-01-01 01:23:34Z'", sql);
+
+    for (sql, data_type) in [
+        (
+            "SELECT TIMESTAMP WITH TIME ZONE '1999-01-01 01:23:34Z'",
+            DataType::Timestamp(None, TimezoneInfo::WithTimeZone),
+        ),
+        (
+            "SELECT TIMESTAMP WITHOUT TIME ZONE '1999-01-01 01:23:34'",
+            DataType::Timestamp(None, TimezoneInfo::WithoutTimeZone),
+        ),
+        (
+            "SELECT TIME WITH TIME ZONE '01:23:34Z'",
+            DataType::Time(None, TimezoneInfo::WithTimeZone),
+        ),
+        (
+            "SELECT TIMETZ '01:23:34Z'",
+            DataType::Time(None, TimezoneInfo::Tz),
+        ),
+    ] {
+        let select = verified_only_select(sql);
+        match expr_from_projection(only(&select.projection)) {
+            Expr::TypedString {
+                data_type: actual, ..
+            } => assert_eq!(actual, &data_type),
+            other => panic!("Expected: typed string, got: {other:?}"),
+        }
+    }
 }
 
 #[test]
@@ -6392,6 +6573,7 @@ fn parse_interval_and_or_xor() {
                 joins: vec![],
             }],
             lateral_views: vec![],
+            array_joins: vec![],
             prewhere: None,
             selection: Some(Expr::BinaryOp {
                 left: Box::new(Expr::BinaryOp {
@@ -7034,6 +7216,91 @@ fn parse_simple_case_expr() {
     );
 }
 
+#[test]
+fn parse_case_expr_forms_round_trip() {
+    // Simple and searched forms keep their shape, and a missing ELSE stays missing
+    let simple = verified_expr("CASE x WHEN 1 THEN 'a' END");
+    let searched = verified_expr("CASE WHEN x = 1 THEN 'a' END");
+    match (&simple, &searched) {
+        (
+            Expr::Case {
+                operand: Some(operand),
+                conditions: simple_conditions,
+                else_result: None,
+                ..
+            },
+            Expr::Case {
+                operand: None,
+                conditions: searched_conditions,
+                else_result: None,
+                ..
+            },
+        ) => {
+            assert_eq!(&Expr::Identifier(Ident::new("x")), operand.as_ref());
+            assert_eq!(Expr::value(number("1")), simple_conditions[0].condition);
+            assert_eq!(
+                Expr::BinaryOp {
+                    left: Box::new(Expr::Identifier(Ident::new("x"))),
+                    op: BinaryOperator::Eq,
+                    right: Box::new(Expr::value(number("1"))),
+                },
+                searched_conditions[0].condition
+            );
+        }
+        _ => panic!("Unexpected CASE expressions: {simple}, {searched}"),
+    }
+    assert_ne!(simple.to_string(), searched.to_string());
+
+    // Nested CASE in the operand, conditions, results and ELSE
+    let nested = verified_expr(
+        "CASE CASE WHEN a THEN 1 ELSE 2 END \
+        WHEN CASE b WHEN 1 THEN 1 END THEN CASE WHEN c THEN 'x' END \
+        ELSE CASE d WHEN 0 THEN 'y' ELSE 'z' END END",
+    );
+    match nested {
+        Expr::Case {
+            operand: Some(operand),
+            conditions,
+            else_result: Some(else_result),
+            ..
+        } => {
+            assert!(matches!(
+                *operand,
+                Expr::Case {
+                    operand: None,
+                    else_result: Some(_),
+                    ..
+                }
+            ));
+            assert!(matches!(
+                conditions[0].condition,
+                Expr::Case {
+                    operand: Some(_),
+                    else_result: None,
+                    ..
+                }
+            ));
+            assert!(matches!(
+                conditions[0].result,
+                Expr::Case {
+                    operand: None,
+                    else_result: None,
+                    ..
+                }
+            ));
+            assert!(matches!(
+                *else_result,
+                Expr::Case {
+                    operand: Some(_),
+                    else_result: Some(_),
+                    ..
+                }
+            ));
+        }
+        _ => unreachable!(),
+    }
+}
+
 #[test]
 fn parse_from_advanced() {
     let sql = "SELECT * FROM fn(1, 2) AS foo, schema.bar AS bar WITH (NOLOCK)";
@@ -7341,6 +7608,30 @@ fn parse_joins_using() {
     verified_stmt("SELECT * FROM tbl1 AS t1 JOIN tbl2 AS t2 USING(t2.col1)");
 }
 
+#[test]
+fn parse_joins_using_multiple_columns() {
+    let select = verified_only_select("SELECT * FROM t1 JOIN t2 USING(a, b, t2.c)");
+    match &only(&select.from).joins[0].join_operator {
+        JoinOperator::Join(JoinConstraint::Using(columns)) => assert_eq!(
+            &vec![
+                ObjectName::from(vec![Ident::new("a")]),
+                ObjectName::from(vec![Ident::new("b")]),
+                ObjectName::from(vec![Ident::new("t2"), Ident::new("c")]),
+            ],
+            columns
+        ),
+        other => panic!("Unexpected join operator: {other:?}"),
+    }
+
+    // The column list is always displayed in its canonical, parenthesized form
+    one_statement_parses_to(
+        "SELECT * FROM t1 LEFT JOIN t2 USING ( a ,b ) JOIN t3 USING (c)",
+        "SELECT * FROM t1 LEFT JOIN t2 USING(a, b) JOIN t3 USING(c)",
+    );
+    assert!(parse_sql_statements("SELECT * FROM t1 JOIN t2 USING a").is_err());
+    assert!(parse_sql_statements("SELECT * FROM t1 JOIN t2 USING ()").is_err());
+}
+
 #[test]
 fn parse_natural_join() {
     fn natural_join(f: impl Fn(JoinConstraint) -> JoinOperator, alias: Option<TableAlias>) -> Join {
@@ -7847,7 +8138,21 @@ fn parse_trim() {
     one_statement_parses_to("SELECT TRIM('   foo   ')", "SELECT TRIM('   foo   ')");
     one_statement_parses_to(
         "SELECT TRIM(LEADING '   foo   ')",
-        "SELECT TRIM(LEADING '   foo   ')",
+        "SELECT TRIM(LEADING FROM '   foo   ')",
+    );
+    verified_stmt("SELECT TRIM(LEADING FROM col)");
+    verified_stmt("SELECT TRIM(TRAILING FROM col)");
+    verified_stmt("SELECT TRIM(BOTH FROM col)");
+    verified_stmt("SELECT TRIM(BOTH 'x' FROM col)");
+    let select = verified_only_select("SELECT TRIM(LEADING FROM col)");
+    assert_eq!(
+        &Expr::Trim {
+            expr: Box::new(Expr::Identifier(Ident::new("col"))),
+            trim_where: Some(TrimWhereField::Leading),
+            trim_what: None,
+            trim_characters: None,
+        },
+        expr_from_projection(only(&select.projection))
     );
 
     assert_eq!(
@@ -7895,7 +8200,34 @@ fn parse_exists_subquery() {
     assert_eq!(
         Expr::Exists {
             negated: true,
-            subquery: Box::new(expected_inner),
+            subquery: Box::new(expected_inner.clone()),
+        },
+        select.selection.unwrap(),
+    );
+
+    // an explicitly parenthesized `EXISTS` is kept distinct from `NOT EXISTS`
+    let sql = "SELECT * FROM t WHERE NOT (EXISTS (SELECT 1))";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        Expr::UnaryOp {
+            op: UnaryOperator::Not,
+            expr: Box::new(Expr::Nested(Box::new(Expr::Exists {
+                negated: false,
+                subquery: Box::new(expected_inner.clone()),
+            }))),
+        },
+        select.selection.unwrap(),
+    );
+
+    let sql = "SELECT * FROM t WHERE NOT NOT EXISTS (SELECT 1)";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        Expr::UnaryOp {
+            op: UnaryOperator::Not,
+            expr: Box::new(Expr::Exists {
+                negated: true,
+                subquery: Box::new(expected_inner),
+            }),
         },
         select.selection.unwrap(),
     );
@@ -7932,12 +8264,14 @@ fn parse_create_database() {
             location,
             managed_location,
             clone,
+            options,
         } => {
             assert_eq!("mydb", db_name.to_string());
             assert!(!if_not_exists);
             assert_eq!(None, location);
             assert_eq!(None, managed_location);
             assert_eq!(None, clone);
+            assert!(options.is_empty());
         }
         _ => unreachable!(),
     }
@@ -7949,6 +8283,7 @@ fn parse_create_database() {
             location,
             managed_location,
             clone,
+            options,
         } => {
             assert_eq!("mydb", db_name.to_string());
             assert!(!if_not_exists);
@@ -7958,6 +8293,7 @@ fn parse_create_database() {
                 Some(ObjectName::from(vec![Ident::new("otherdb".to_string())])),
                 clone
             );
+            assert!(options.is_empty());
         }
         _ => unreachable!(),
     }
@@ -7973,12 +8309,14 @@ fn parse_create_database_ine() {
             location,
             managed_location,
             clone,
+            options,
         } => {
             assert_eq!("mydb", db_name.to_string());
             assert!(if_not_exists);
             assert_eq!(None, location);
             assert_eq!(None, managed_location);
             assert_eq!(None, clone);
+            assert!(options.is_empty());
         }
         _ => unreachable!(),
     }
@@ -8040,6 +8378,7 @@ fn parse_create_view() {
             temporary,
             to,
             params,
+            with_check_option,
         } => {
             assert_eq!(or_alter, false);
             assert_eq!("myschema.myview", name.to_string());
@@ -8055,6 +8394,7 @@ fn parse_create_view() {
             assert!(!temporary);
             assert!(to.is_none());
             assert!(params.is_none());
+            assert!(with_check_option.is_none());
         }
         _ => unreachable!(),
     }
@@ -8108,6 +8448,7 @@ fn parse_create_view_with_columns() {
             temporary,
             to,
             params,
+            with_check_option,
         } => {
             assert_eq!(or_alter, false);
             assert_eq!("v", name.to_string());
@@ -8133,6 +8474,7 @@ fn parse_create_view_with_columns() {
             assert!(!temporary);
             assert!(to.is_none());
             assert!(params.is_none());
+            assert!(with_check_option.is_none());
         }
         _ => unreachable!(),
     }
@@ -8157,6 +8499,7 @@ fn parse_create_view_temporary() {
             temporary,
             to,
             params,
+            with_check_option,
         } => {
             assert_eq!(or_alter, false);
             assert_eq!("myschema.myview", name.to_string());
@@ -8172,6 +8515,7 @@ fn parse_create_view_temporary() {
             assert!(temporary);
             assert!(to.is_none());
             assert!(params.is_none());
+            assert!(with_check_option.is_none());
         }
         _ => unreachable!(),
     }
@@ -8196,6 +8540,7 @@ fn parse_create_or_replace_view() {
             temporary,
             to,
             params,
+            with_check_option,
         } => {
             assert_eq!(or_alter, false);
             assert_eq!("v", name.to_string());
@@ -8211,6 +8556,7 @@ fn parse_create_or_replace_view() {
             assert!(!temporary);
             assert!(to.is_none());
             assert!(params.is_none());
+            assert!(with_check_option.is_none());
         }
         _ => unreachable!(),
     }
@@ -8239,6 +8585,7 @@ fn parse_create_or_replace_materialized_view() {
             temporary,
             to,
             params,
+            with_check_option,
         } => {
             assert_eq!(or_alter, false);
             assert_eq!("v", name.to_string());
@@ -8254,6 +8601,7 @@ fn parse_create_or_replace_materialized_view() {
             assert!(!temporary);
             assert!(to.is_none());
             assert!(params.is_none());
+            assert!(with_check_option.is_none());
         }
         _ => unreachable!(),
     }
@@ -8278,6 +8626,7 @@ fn parse_create_materialized_view() {
             temporary,
             to,
             params,
+            with_check_option,
         } => {
             assert_eq!(or_alter, false);
             assert_eq!("myschema.myview", name.to_string());
@@ -8293,6 +8642,7 @@ fn parse_create_materialized_view() {
             assert!(!temporary);
             assert!(to.is_none());
             assert!(params.is_none());
+            assert!(with_check_option.is_none());
         }
         _ => unreachable!(),
     }
@@ -8317,6 +8667,7 @@ fn parse_create_materialized_view_with_cluster_by() {
             temporary,
             to,
             params,
+            with_check_option,
         } => {
             assert_eq!(or_alter, false);
             assert_eq!("myschema.myview", name.to_string());
@@ -8332,6 +8683,7 @@ fn parse_create_materialized_view_with_cluster_by() {
             assert!(!temporary);
             assert!(to.is_none());
             assert!(params.is_none());
+            assert!(with_check_option.is_none());
         }
         _ => unreachable!(),
     }
@@ -8369,6 +8721,7 @@ fn parse_drop_table() {
             if_exists,
             names,
             cascade,
+            cascade_constraints,
             purge: _,
             temporary,
             ..
@@ -8380,11 +8733,29 @@ fn parse_drop_table() {
                 names.iter().map(ToString::to_string).collect::<Vec<_>>()
             );
             assert!(cascade);
+            assert!(!cascade_constraints);
             assert!(!temporary);
         }
         _ => unreachable!(),
     }
 
+    let sql = "DROP TABLE foo CASCADE CONSTRAINTS";
+    match verified_stmt(sql) {
+        Statement::Drop {
+            names,
+            cascade,
+            cascade_constraints,
+            restrict,
+            ..
+        } => {
+            assert_eq_vec(&["foo"], &names);
+            assert!(cascade);
+            assert!(cascade_constraints);
+            assert!(!restrict);
+        }
+        _ => unreachable!(),
+    }
+
     let sql = "DROP TABLE";
     assert_eq!(
         ParserError::ParserError("Expected: identifier, found: EOF".to_string()),
@@ -8606,6 +8977,39 @@ fn parse_fetch() {
     }
 }
 
+#[test]
+fn parse_fetch_with_ties_and_order_by() {
+    // `WITH TIES` needs an `ORDER BY` to be meaningful, but that is left
+    // to semantic analysis; the parser accepts and round-trips the combination
+    let ast = verified_query("SELECT x FROM t ORDER BY x FETCH FIRST 5 ROWS WITH TIES");
+    assert_eq!(
+        Some(OrderBy {
+            kind: OrderByKind::Expressions(vec![OrderByExpr {
+                expr: Expr::Identifier(Ident::new("x")),
+                options: OrderByOptions {
+                    asc: None,
+                    nulls_first: None,
+                },
+                with_fill: None,
+            }]),
+            interpolate: None,
+        }),
+        ast.order_by
+    );
+    assert_eq!(
+        Some(Fetch {
+            with_ties: true,
+            percent: false,
+            quantity: Some(Expr::value(number("5"))),
+        }),
+        ast.fetch
+    );
+
+    verified_stmt("SELECT x FROM t ORDER BY x DESC, y OFFSET 10 ROWS FETCH FIRST 5 ROWS WITH TIES");
+    verified_stmt("SELECT x FROM t ORDER BY x FETCH FIRST 10 PERCENT ROWS WITH TIES");
+    verified_stmt("SELECT x FROM t FETCH FIRST 5 ROWS WITH TIES");
+}
+
 #[test]
 fn parse_fetch_variations() {
     one_statement_parses_to(
@@ -8667,6 +9071,28 @@ fn lateral_derived() {
     chk(false);
     chk(true);
 
+    // the keyword is kept for lateral derived tables in comma and cross joins
+    let select = verified_only_select(
+        "SELECT * FROM customer, LATERAL (SELECT * FROM orders WHERE orders.customer = customer.id) AS o",
+    );
+    assert_eq!(2, select.from.len());
+    assert!(matches!(
+        select.from[1].relation,
+        TableFactor::Derived { lateral: true, .. }
+    ));
+
+    let select = verified_only_select(
+        "SELECT * FROM customer CROSS JOIN LATERAL (SELECT * FROM orders WHERE orders.customer = customer.id) AS o",
+    );
+    let from = only(select.from);
+    assert_eq!(JoinOperator::CrossJoin, from.joins[0].join_operator);
+    assert!(matches!(
+        from.joins[0].relation,
+        TableFactor::Derived { lateral: true, .. }
+    ));
+
+    verified_stmt("SELECT * FROM a, LATERAL (SELECT a.x) AS s, b");
+
     let sql = "SELECT * FROM LATERAL UNNEST ([10,20,30]) as numbers WITH OFFSET;";
     let res = parse_sql_statements(sql);
     assert_eq!(
@@ -8721,6 +9147,7 @@ fn lateral_function() {
             }],
         }],
         lateral_views: vec![],
+        array_joins: vec![],
         prewhere: None,
         selection: None,
         group_by: GroupByExpr::Expressions(vec![], vec![]),
@@ -9703,6 +10130,7 @@ fn parse_merge() {
                                 joins: vec![],
                             }],
                             lateral_views: vec![],
+                            array_joins: vec![],
                             prewhere: None,
                             selection: None,
                             group_by: GroupByExpr::Expressions(vec![], vec![]),
@@ -10217,6 +10645,7 @@ fn parse_time_functions() {
                 args: vec![],
                 clauses: vec![],
             }),
+            from_first: None,
             null_treatment: None,
             filter: None,
             over: None,
@@ -11446,6 +11875,7 @@ fn parse_call() {
             }),
             name: ObjectName::from(vec![Ident::new("my_procedure")]),
             filter: None,
+            from_first: None,
             null_treatment: None,
             over: None,
             within_group: vec![],
@@ -11633,6 +12063,7 @@ fn parse_unload() {
                         joins: vec![],
                     }],
                     lateral_views: vec![],
+                    array_joins: vec![],
                     prewhere: None,
                     selection: None,
                     group_by: GroupByExpr::Expressions(vec![], vec![]),
@@ -11800,6 +12231,7 @@ fn parse_map_access_expr() {
                         clauses: vec![],
                     }),
                     filter: None,
+                    from_first: None,
                     null_treatment: None,
                     over: None,
                     within_group: vec![],
@@ -11834,6 +12266,7 @@ fn parse_connect_by() {
         }],
         into: None,
         lateral_views: vec![],
+        array_joins: vec![],
         prewhere: None,
         selection: None,
         group_by: GroupByExpr::Expressions(vec![], vec![]),
@@ -11860,6 +12293,7 @@ fn parse_connect_by() {
                     "employee_id",
                 ))))),
             }],
+            nocycle: false,
         }),
         flavor: SelectFlavor::Standard,
     };
@@ -11916,6 +12350,7 @@ fn parse_connect_by() {
             }],
             into: None,
             lateral_views: vec![],
+            array_joins: vec![],
             prewhere: None,
             selection: Some(Expr::BinaryOp {
                 left: Box::new(Expr::Identifier(Ident::new("employee_id"))),
@@ -11946,6 +12381,7 @@ fn parse_connect_by() {
                         "employee_id",
                     ))))),
                 }],
+                nocycle: false,
             }),
             flavor: SelectFlavor::Standard,
         }
@@ -12015,6 +12451,7 @@ fn test_selective_aggregation() {
                 ))))),
                 over: None,
                 within_group: vec![],
+                from_first: None,
                 null_treatment: None
             })),
             SelectItem::ExprWithAlias {
@@ -12038,6 +12475,7 @@ fn test_selective_aggregation() {
                         escape_char: None,
                         any: false,
                     })),
+                    from_first: None,
                     null_treatment: None,
                     over: None,
                     within_group: vec![]
@@ -12045,7 +12483,32 @@ fn test_selective_aggregation() {
                 alias: Ident::new("agg2")
             },
         ]
-    )
+    );
+
+    let select = testing_dialects.verified_only_select(
+        "SELECT COUNT(*) FILTER (WHERE x > 0), SUM(a) FILTER (WHERE b) FROM t",
+    );
+    match expr_from_projection(&select.projection[0]) {
+        Expr::Function(Function { filter, .. }) => {
+            assert_eq!(
+                Some("x > 0".to_string()),
+                filter.as_ref().map(|f| f.to_string())
+            )
+        }
+        _ => unreachable!(),
+    }
+
+    // `FILTER` is only treated as a clause when followed by a parenthesis
+    testing_dialects.one_statement_parses_to(
+        "SELECT COUNT(*) filter FROM t",
+        "SELECT COUNT(*) AS filter FROM t",
+    );
+    assert_eq!(
+        ParserError::ParserError("Expected: WHERE, found: x".to_string()),
+        testing_dialects
+            .parse_sql_statements("SELECT COUNT(*) FILTER (x > 0) FROM t")
+            .unwrap_err()
+    );
 }
 
 #[test]
@@ -12075,6 +12538,36 @@ fn test_group_by_grouping_sets() {
     );
 }
 
+#[test]
+fn test_group_by_grouping_sets_with_rollup_and_cube() {
+    let sql = "SELECT a, b, c FROM t GROUP BY GROUPING SETS (ROLLUP (a, b), CUBE (c), (a, c), ())";
+    assert_eq!(
+        all_dialects_where(|d| d.supports_group_by_expr())
+            .verified_only_select(sql)
+            .group_by,
+        GroupByExpr::Expressions(
+            vec![Expr::GroupingSets(vec![
+                vec![Expr::Rollup(vec![
+                    vec![Expr::Identifier(Ident::new("a"))],
+                    vec![Expr::Identifier(Ident::new("b"))],
+                ])],
+                vec![Expr::Cube(vec![vec![Expr::Identifier(Ident::new("c"))]])],
+                vec![
+                    Expr::Identifier(Ident::new("a")),
+                    Expr::Identifier(Ident::new("c"))
+                ],
+                vec![]
+            ])],
+            vec![]
+        )
+    );
+
+    all_dialects_where(|d| d.supports_group_by_expr()).one_statement_parses_to(
+        "SELECT a FROM t GROUP BY GROUPING SETS (ROLLUP(a, (b, c)), CUBE(a))",
+        "SELECT a FROM t GROUP BY GROUPING SETS (ROLLUP (a, (b, c)), CUBE (a))",
+    );
+}
+
 #[test]
 fn test_xmltable() {
     all_dialects()
@@ -12847,6 +13340,7 @@ fn test_extract_seconds_ok() {
             into: None,
             from: vec![],
             lateral_views: vec![],
+            array_joins: vec![],
             prewhere: None,
             selection: None,
             group_by: GroupByExpr::Expressions(vec![], vec![]),
@@ -14283,6 +14777,7 @@ fn parse_composite_access_expr() {
                     ))],
                     clauses: vec![],
                 }),
+                from_first: None,
                 null_treatment: None,
                 filter: None,
                 over: None,
@@ -14307,6 +14802,7 @@ fn parse_composite_access_expr() {
                     ))],
                     clauses: vec![],
                 }),
+                from_first: None,
                 null_treatment: None,
                 filter: None,
                 over: None,
@@ -14333,6 +14829,7 @@ fn parse_composite_access_expr() {
                 ))],
                 clauses: vec![],
             }),
+            from_first: None,
             null_treatment: None,
             filter: None,
             over: None,
@@ -14487,6 +14984,41 @@ fn test_table_sample() {
     dialects.verified_stmt("SELECT * FROM tbl AS t TABLESAMPLE BERNOULLI (50)");
     dialects.verified_stmt("SELECT * FROM tbl AS t TABLESAMPLE SYSTEM (50)");
     dialects.verified_stmt("SELECT * FROM tbl AS t TABLESAMPLE SYSTEM (50) REPEATABLE (10)");
+
+    let sample_after_alias = "SELECT * FROM tbl AS t TABLESAMPLE (50)";
+    let select = all_dialects_where(|d| d.supports_table_sample_after_alias())
+        .verified_only_select(sample_after_alias);
+    match &select.from[0].relation {
+        TableFactor::Table {
+            alias,
+            sample: Some(TableSampleKind::AfterTableAlias(_)),
+            ..
+        } => assert_eq!(alias.as_ref().unwrap().name.value, "t"),
+        other => panic!("unexpected table factor: {other:?}"),
+    }
+
+    // the sample before the alias keeps its placement where supported
+    let sample_before_alias = "SELECT * FROM tbl TABLESAMPLE (50) AS t";
+    let select = all_dialects_where(|d| d.supports_table_sample_before_alias())
+        .verified_only_select(sample_before_alias);
+    match &select.from[0].relation {
+        TableFactor::Table {
+            alias,
+            sample: Some(TableSampleKind::BeforeTableAlias(_)),
+            ..
+        } => assert_eq!(alias.as_ref().unwrap().name.value, "t"),
+        other => panic!("unexpected table factor: {other:?}"),
+    }
+    assert!(
+        all_dialects_where(|d| !d.supports_table_sample_before_alias())
+            .parse_sql_statements(sample_before_alias)
+            .is_err()
+    );
+    assert!(
+        all_dialects_where(|d| !d.supports_table_sample_after_alias())
+            .parse_sql_statements(sample_after_alias)
+            .is_err()
+    );
 }
 
 #[test]
@@ -14770,6 +15302,74 @@ fn test_if_statement_multiline_span() {
     );
 }
 
+#[test]
+fn test_parse_statements_with_locations() {
+    let sql = "SELECT 1;\nINSERT INTO t VALUES (1) ;  \n-- comment\nUPDATE t SET a = 2;";
+    let statements = Parser::new(&GenericDialect {})
+        .try_with_sql(sql)
+        .unwrap()
+        .parse_statements_with_locations()
+        .unwrap();
+    let spans: Vec<Span> = statements.iter().map(|(_, span)| *span).collect();
+    assert_eq!(
+        spans,
+        vec![
+            Span::new(Location::new(1, 1), Location::new(1, 9)),
+            Span::new(Location::new(2, 1), Location::new(2, 25)),
+            Span::new(Location::new(4, 1), Location::new(4, 19)),
+        ]
+    );
+    for pair in spans.windows(2) {
+        assert!(pair[0].end < pair[1].start);
+    }
+    assert_eq!(
+        statements
+            .into_iter()
+            .map(|(statement, _)| statement)
+            .collect::<Vec<_>>(),
+        Parser::parse_sql(&GenericDialect {}, sql).unwrap()
+    );
+}
+
+#[test]
+fn test_parse_statements_for_each() {
+    use core::ops::ControlFlow;
+
+    let sql = (0..1000)
+        .map(|i| format!("SELECT {i}"))
+        .collect::<Vec<_>>()
+        .join("; ");
+    let dialect = GenericDialect {};
+
+    let mut count = 0;
+    Parser::new(&dialect)
+        .try_with_sql(&sql)
+        .unwrap()
+        .parse_statements_for_each(|statement| {
+            assert_eq!(statement.to_string(), format!("SELECT {count}"));
+            count += 1;
+            ControlFlow::Continue(())
+        })
+        .unwrap();
+    assert_eq!(count, 1000);
+
+    // breaking stops parsing early, even before invalid input
+    let mut count = 0;
+    Parser::new(&dialect)
+        .try_with_sql("SELECT 1; SELECT 2; NOT VALID SQL")
+        .unwrap()
+        .parse_statements_for_each(|_| {
+            count += 1;
+            if count == 2 {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        })
+        .unwrap();
+    assert_eq!(count, 2);
+}
+
 #[test]
 fn test_conditional_statement_span() {
     let sql = "IF 1=1 THEN SELECT 1; ELSEIF 1=2 THEN SELECT 2; ELSE SELECT 3; END IF";
@@ -14928,6 +15528,7 @@ fn test_select_from_first() {
                     joins: vec![],
                 }],
                 lateral_views: vec![],
+                array_joins: vec![],
                 prewhere: None,
                 selection: None,
                 group_by: GroupByExpr::Expressions(vec![], vec![]),
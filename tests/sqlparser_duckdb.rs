@@ -281,6 +281,7 @@ fn test_select_union_by_name() {
                     joins: vec![],
                 }],
                 lateral_views: vec![],
+                array_joins: vec![],
                 prewhere: None,
                 selection: None,
                 group_by: GroupByExpr::Expressions(vec![], vec![]),
@@ -312,6 +313,7 @@ fn test_select_union_by_name() {
                     joins: vec![],
                 }],
                 lateral_views: vec![],
+                array_joins: vec![],
                 prewhere: None,
                 selection: None,
                 group_by: GroupByExpr::Expressions(vec![], vec![]),
@@ -331,6 +333,32 @@ fn test_select_union_by_name() {
     }
 }
 
+#[test]
+fn test_select_set_operations_by_name_chained() {
+    let query = duckdb().verified_query(
+        "SELECT a FROM t1 UNION BY NAME SELECT a FROM t2 UNION ALL BY NAME SELECT a FROM t3",
+    );
+    match *query.body {
+        SetExpr::SetOperation {
+            op: SetOperator::Union,
+            set_quantifier: SetQuantifier::AllByName,
+            left,
+            ..
+        } => assert!(matches!(
+            *left,
+            SetExpr::SetOperation {
+                op: SetOperator::Union,
+                set_quantifier: SetQuantifier::ByName,
+                ..
+            }
+        )),
+        _ => unreachable!(),
+    }
+
+    duckdb().verified_stmt("SELECT a FROM t1 EXCEPT BY NAME SELECT a FROM t2");
+    duckdb().verified_stmt("SELECT a FROM t1 INTERSECT ALL BY NAME SELECT a FROM t2");
+}
+
 #[test]
 fn test_duckdb_install() {
     let stmt = duckdb().verified_stmt("INSTALL tpch");
@@ -649,6 +677,7 @@ fn test_duckdb_named_argument_function_with_assignment_operator() {
                 ],
                 clauses: vec![],
             }),
+            from_first: None,
             null_treatment: None,
             filter: None,
             over: None,
@@ -737,6 +766,8 @@ fn test_duckdb_union_datatype() {
                 }
             ],
             constraints: Default::default(),
+            period_for_system_time: None,
+            with_system_versioning: false,
             hive_distribution: HiveDistributionStyle::NONE,
             hive_formats: Some(HiveFormat {
                 row_format: Default::default(),
@@ -756,6 +787,9 @@ fn test_duckdb_union_datatype() {
             primary_key: Default::default(),
             order_by: Default::default(),
             partition_by: Default::default(),
+            partition_strategy: Default::default(),
+            partition_of: Default::default(),
+            for_values: Default::default(),
             cluster_by: Default::default(),
             clustered_by: Default::default(),
             inherits: Default::default(),
@@ -862,3 +896,12 @@ fn test_duckdb_trim() {
         duckdb().parse_sql_statements(error_sql).unwrap_err()
     );
 }
+
+#[test]
+fn test_pragma() {
+    duckdb().verified_stmt("PRAGMA table_info(t)");
+    duckdb().verified_stmt("PRAGMA table_info('t')");
+    duckdb().verified_stmt("PRAGMA threads = 4");
+    duckdb().verified_stmt("PRAGMA enable_profiling");
+    duckdb().verified_stmt("PRAGMA default_order = DESC");
+}
@@ -497,6 +497,7 @@ fn parse_delimited_identifiers() {
                 args: vec![],
                 clauses: vec![],
             }),
+            from_first: None,
             null_treatment: None,
             filter: None,
             over: None,
@@ -554,6 +555,10 @@ fn test_tample_sample() {
     hive().verified_stmt("SELECT * FROM source TABLESAMPLE (100M) AS s");
     hive().verified_stmt("SELECT * FROM source TABLESAMPLE (0.1 PERCENT) AS s");
     hive().verified_stmt("SELECT * FROM source TABLESAMPLE (10 ROWS)");
+    // Hive only accepts the sample before the alias
+    assert!(hive()
+        .parse_sql_statements("SELECT * FROM source AS s TABLESAMPLE (10 ROWS)")
+        .is_err());
 }
 
 fn hive() -> TestedDialects {
@@ -85,6 +85,51 @@ fn parse_table_time_travel() {
     assert!(ms().parse_sql_statements(&sql).is_err());
 }
 
+#[test]
+fn parse_table_time_travel_periods() {
+    let ts = |s: &str| Expr::Value(Value::SingleQuotedString(s.to_string()).with_empty_span());
+    for (sql, expected) in [
+        (
+            "SELECT * FROM t FOR SYSTEM_TIME BETWEEN '2020-01-01' AND '2021-01-01'",
+            TableVersion::ForSystemTimeBetween {
+                low: ts("2020-01-01"),
+                high: ts("2021-01-01"),
+            },
+        ),
+        (
+            "SELECT * FROM t FOR SYSTEM_TIME FROM '2020-01-01' TO '2021-01-01'",
+            TableVersion::ForSystemTimeFromTo {
+                from: ts("2020-01-01"),
+                to: ts("2021-01-01"),
+            },
+        ),
+        (
+            "SELECT * FROM t FOR SYSTEM_TIME CONTAINED IN ('2020-01-01', '2021-01-01')",
+            TableVersion::ForSystemTimeContainedIn {
+                from: ts("2020-01-01"),
+                to: ts("2021-01-01"),
+            },
+        ),
+        (
+            "SELECT * FROM t FOR SYSTEM_TIME ALL",
+            TableVersion::ForSystemTimeAll,
+        ),
+    ] {
+        let select = ms_and_generic().verified_only_select(sql);
+        match only(select.from).relation {
+            TableFactor::Table { version, .. } => assert_eq!(version, Some(expected)),
+            _ => unreachable!(),
+        }
+    }
+
+    ms_and_generic().verified_stmt(
+        "SELECT * FROM t FOR SYSTEM_TIME BETWEEN '2020-01-01' AND '2021-01-01' WHERE a = 1 AND b = 2",
+    );
+    assert!(ms()
+        .parse_sql_statements("SELECT * FROM t FOR SYSTEM_TIME LATEST")
+        .is_err());
+}
+
 #[test]
 fn parse_mssql_single_quoted_aliases() {
     let _ = ms_and_generic().one_statement_parses_to("SELECT foo 'alias'", "SELECT foo AS 'alias'");
@@ -130,6 +175,7 @@ fn parse_create_procedure() {
                         into: None,
                         from: vec![],
                         lateral_views: vec![],
+                        array_joins: vec![],
                         prewhere: None,
                         selection: None,
                         group_by: GroupByExpr::Expressions(vec![], vec![]),
@@ -755,6 +801,24 @@ fn parse_mssql_top_percent_with_ties() {
         top.quantity
     );
     assert!(top.percent);
+    assert!(top.with_ties);
+
+    let sql = "SELECT TOP 10 PERCENT WITH TIES * FROM foo ORDER BY bar";
+    let select = ms_and_generic().verified_only_select(sql);
+    let top = select.top.unwrap();
+    assert_eq!(Some(TopQuantity::Constant(10)), top.quantity);
+    assert!(top.percent);
+    assert!(top.with_ties);
+
+    let sql = "SELECT TOP (@n) WITH TIES * FROM foo ORDER BY bar";
+    let select = ms().verified_only_select(sql);
+    let top = select.top.unwrap();
+    assert_eq!(
+        Some(TopQuantity::Expr(Expr::Identifier(Ident::new("@n")))),
+        top.quantity
+    );
+    assert!(!top.percent);
+    assert!(top.with_ties);
 }
 
 #[test]
@@ -763,6 +827,39 @@ fn parse_mssql_top() {
     let _ = ms_and_generic().one_statement_parses_to(sql, "SELECT TOP 5 bar, baz FROM foo");
 }
 
+#[test]
+fn parse_mssql_select_into_temp_table() {
+    let select = ms_and_generic().verified_only_select("SELECT * INTO #tmp FROM t");
+    let into = select.into.unwrap();
+    assert_eq!(
+        SelectInto {
+            temporary: false,
+            unlogged: false,
+            table: false,
+            name: ObjectName::from(vec![Ident::new("#tmp")]),
+        },
+        into
+    );
+    assert!(into.is_local_temp_table());
+    assert!(!into.is_global_temp_table());
+
+    let select = ms_and_generic().verified_only_select("SELECT a, b INTO ##global_tmp FROM t");
+    let into = select.into.unwrap();
+    assert_eq!(
+        ObjectName::from(vec![Ident::new("##global_tmp")]),
+        into.name
+    );
+    assert!(!into.is_local_temp_table());
+    assert!(into.is_global_temp_table());
+
+    let select = ms_and_generic().verified_only_select("SELECT * INTO dbo.t2 FROM t");
+    let into = select.into.unwrap();
+    assert!(!into.is_local_temp_table());
+    assert!(!into.is_global_temp_table());
+
+    ms().verified_stmt("SELECT * INTO [#tmp] FROM t WHERE a > 1");
+}
+
 #[test]
 fn parse_mssql_bin_literal() {
     let _ = ms_and_generic().one_statement_parses_to("SELECT 0xdeadBEEF", "SELECT X'deadBEEF'");
@@ -897,6 +994,7 @@ fn parse_delimited_identifiers() {
                 args: vec![],
                 clauses: vec![],
             }),
+            from_first: None,
             null_treatment: None,
             filter: None,
             over: None,
@@ -1380,6 +1478,7 @@ fn parse_substring_in_select() {
                             joins: vec![]
                         }],
                         lateral_views: vec![],
+                        array_joins: vec![],
                         prewhere: None,
                         selection: None,
                         group_by: GroupByExpr::Expressions(vec![], vec![]),
@@ -1522,6 +1621,7 @@ fn parse_mssql_declare() {
                     into: None,
                     from: vec![],
                     lateral_views: vec![],
+                    array_joins: vec![],
                     prewhere: None,
                     selection: None,
                     group_by: GroupByExpr::Expressions(vec![], vec![]),
@@ -1829,6 +1929,7 @@ fn parse_create_table_with_valid_options() {
                                 },
                             ),
                             filter: None,
+                            from_first: None,
                             null_treatment: None,
                             over: None,
                             within_group: vec![],
@@ -1886,6 +1987,8 @@ fn parse_create_table_with_valid_options() {
                     },
                 ],
                 constraints: vec![],
+                period_for_system_time: None,
+                with_system_versioning: false,
                 hive_distribution: HiveDistributionStyle::NONE,
                 hive_formats: Some(HiveFormat {
                     row_format: None,
@@ -1905,6 +2008,9 @@ fn parse_create_table_with_valid_options() {
                 primary_key: None,
                 order_by: None,
                 partition_by: None,
+                partition_strategy: None,
+                partition_of: None,
+                for_values: None,
                 cluster_by: None,
                 clustered_by: None,
                 inherits: None,
@@ -1946,6 +2052,41 @@ fn parse_nested_slash_star_comment() {
     ms().one_statement_parses_to(sql, canonical);
 }
 
+#[test]
+fn parse_create_table_with_system_versioning() {
+    let sql = "CREATE TABLE dbo.t (id INT PRIMARY KEY, \
+        valid_from DATETIME2 GENERATED ALWAYS AS ROW START NOT NULL, \
+        valid_to DATETIME2 GENERATED ALWAYS AS ROW END NOT NULL, \
+        PERIOD FOR SYSTEM_TIME (valid_from, valid_to)) \
+        WITH (SYSTEM_VERSIONING = ON)";
+    match ms_and_generic().verified_stmt(sql) {
+        Statement::CreateTable(CreateTable {
+            period_for_system_time,
+            with_system_versioning,
+            table_options,
+            ..
+        }) => {
+            // the MsSql form is a table option rather than `WITH SYSTEM VERSIONING`
+            assert!(!with_system_versioning);
+            assert_eq!(
+                table_options,
+                CreateTableOptions::With(vec![SqlOption::KeyValue {
+                    key: Ident::new("SYSTEM_VERSIONING"),
+                    value: Expr::Identifier(Ident::new("ON")),
+                }])
+            );
+            assert_eq!(
+                Some(PeriodForSystemTime {
+                    start: Ident::new("valid_from"),
+                    end: Ident::new("valid_to"),
+                }),
+                period_for_system_time
+            );
+        }
+        _ => unreachable!(),
+    }
+}
+
 #[test]
 fn parse_create_table_with_invalid_options() {
     let invalid_cases = vec![
@@ -2051,6 +2192,8 @@ fn parse_create_table_with_identity_column() {
                     options: column_options,
                 },],
                 constraints: vec![],
+                period_for_system_time: None,
+                with_system_versioning: false,
                 hive_distribution: HiveDistributionStyle::NONE,
                 hive_formats: Some(HiveFormat {
                     row_format: None,
@@ -2070,6 +2213,9 @@ fn parse_create_table_with_identity_column() {
                 primary_key: None,
                 order_by: None,
                 partition_by: None,
+                partition_strategy: None,
+                partition_of: None,
+                for_values: None,
                 cluster_by: None,
                 clustered_by: None,
                 inherits: None,
@@ -555,6 +555,65 @@ fn parse_show_create() {
             }
         );
     }
+
+    assert_eq!(
+        mysql_and_generic().verified_stmt("SHOW CREATE TABLE mydb.`my table`"),
+        Statement::ShowCreate {
+            obj_type: ShowCreateObject::Table,
+            obj_name: ObjectName::from(vec![
+                Ident::new("mydb"),
+                Ident::with_quote('`', "my table"),
+            ]),
+        }
+    );
+}
+
+#[test]
+fn parse_show_index() {
+    assert_eq!(
+        mysql_and_generic().verified_stmt("SHOW INDEX FROM mytable"),
+        Statement::ShowIndex {
+            extended: false,
+            keyword: ShowIndexKeyword::Index,
+            show_options: ShowStatementOptions {
+                show_in: Some(ShowStatementIn {
+                    clause: ShowStatementInClause::FROM,
+                    parent_type: None,
+                    parent_name: Some(ObjectName::from(vec![Ident::new("mytable")])),
+                }),
+                filter_position: None,
+                limit_from: None,
+                limit: None,
+                starts_with: None,
+            }
+        }
+    );
+    mysql_and_generic().verified_stmt("SHOW EXTENDED INDEX IN mydb.mytable");
+    mysql_and_generic().verified_stmt("SHOW INDEX FROM mytable WHERE Key_name = 'PRIMARY'");
+    mysql_and_generic().verified_stmt("SHOW INDEXES FROM mytable");
+    mysql_and_generic().verified_stmt("SHOW KEYS IN mytable");
+    mysql_and_generic().one_statement_parses_to(
+        "SHOW INDEXES FROM mytable FROM mydb",
+        "SHOW INDEXES FROM mydb.mytable",
+    );
+    match mysql_and_generic().verified_stmt("SHOW EXTENDED KEYS FROM mytable") {
+        Statement::ShowIndex {
+            extended, keyword, ..
+        } => {
+            assert!(extended);
+            assert_eq!(keyword, ShowIndexKeyword::Keys);
+        }
+        _ => unreachable!(),
+    }
+    match mysql_and_generic().verified_stmt("SHOW INDEXES IN mytable") {
+        Statement::ShowIndex { keyword, .. } => {
+            assert_eq!(keyword, ShowIndexKeyword::Indexes);
+        }
+        _ => unreachable!(),
+    }
+    assert!(mysql_and_generic()
+        .parse_sql_statements("SHOW FULL INDEX FROM mytable")
+        .is_err());
 }
 
 #[test]
@@ -625,6 +684,30 @@ fn parse_set_variables() {
     );
 }
 
+#[test]
+fn parse_create_procedure_with_parameter_modes() {
+    let sql = "CREATE PROCEDURE p (OUT total BIGINT, a INT, IN b TEXT, INOUT c INT) AS BEGIN SELECT 1; END";
+    match mysql().verified_stmt(sql) {
+        Statement::CreateProcedure { params, .. } => {
+            let modes = params
+                .unwrap()
+                .into_iter()
+                .map(|p| (p.name.value, p.mode))
+                .collect::<Vec<_>>();
+            assert_eq!(
+                vec![
+                    ("total".to_string(), Some(ArgMode::Out)),
+                    ("a".to_string(), None),
+                    ("b".to_string(), Some(ArgMode::In)),
+                    ("c".to_string(), Some(ArgMode::InOut)),
+                ],
+                modes
+            );
+        }
+        _ => unreachable!(),
+    }
+}
+
 #[test]
 fn parse_create_table_auto_increment() {
     let sql = "CREATE TABLE foo (bar INT PRIMARY KEY AUTO_INCREMENT)";
@@ -945,6 +1028,61 @@ fn parse_create_table_comment() {
     }
 }
 
+#[test]
+fn parse_create_table_with_system_versioning() {
+    let sql = "CREATE TABLE t (x INT, \
+        start_ts TIMESTAMP(6) GENERATED ALWAYS AS ROW START, \
+        end_ts TIMESTAMP(6) GENERATED ALWAYS AS ROW END, \
+        PERIOD FOR SYSTEM_TIME (start_ts, end_ts)) \
+        WITH SYSTEM VERSIONING";
+    match mysql_and_generic().verified_stmt(sql) {
+        Statement::CreateTable(CreateTable {
+            columns,
+            period_for_system_time,
+            with_system_versioning,
+            ..
+        }) => {
+            assert!(with_system_versioning);
+            assert_eq!(
+                Some(PeriodForSystemTime {
+                    start: Ident::new("start_ts"),
+                    end: Ident::new("end_ts"),
+                }),
+                period_for_system_time
+            );
+            assert_eq!(
+                vec![ColumnOptionDef {
+                    name: None,
+                    option: ColumnOption::Generated {
+                        generated_as: GeneratedAs::RowStart,
+                        sequence_options: None,
+                        generation_expr: None,
+                        generation_expr_mode: None,
+                        generated_keyword: true,
+                    },
+                }],
+                columns[1].options
+            );
+            assert_eq!(
+                vec![ColumnOptionDef {
+                    name: None,
+                    option: ColumnOption::Generated {
+                        generated_as: GeneratedAs::RowEnd,
+                        sequence_options: None,
+                        generation_expr: None,
+                        generation_expr_mode: None,
+                        generated_keyword: true,
+                    },
+                }],
+                columns[2].options
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    mysql_and_generic().verified_stmt("CREATE TABLE t (x INT) WITH SYSTEM VERSIONING");
+}
+
 #[test]
 fn parse_create_table_auto_increment_offset() {
     let sql =
@@ -1407,6 +1545,7 @@ fn parse_escaped_quote_identifiers_with_escape() {
                 into: None,
                 from: vec![],
                 lateral_views: vec![],
+                array_joins: vec![],
                 prewhere: None,
                 selection: None,
                 group_by: GroupByExpr::Expressions(vec![], vec![]),
@@ -1462,6 +1601,7 @@ fn parse_escaped_quote_identifiers_with_no_escape() {
                 into: None,
                 from: vec![],
                 lateral_views: vec![],
+                array_joins: vec![],
                 prewhere: None,
                 selection: None,
                 group_by: GroupByExpr::Expressions(vec![], vec![]),
@@ -1510,6 +1650,7 @@ fn parse_escaped_backticks_with_escape() {
                 into: None,
                 from: vec![],
                 lateral_views: vec![],
+                array_joins: vec![],
                 prewhere: None,
                 selection: None,
                 group_by: GroupByExpr::Expressions(vec![], vec![]),
@@ -1562,6 +1703,7 @@ fn parse_escaped_backticks_with_no_escape() {
                 into: None,
                 from: vec![],
                 lateral_views: vec![],
+                array_joins: vec![],
                 prewhere: None,
                 selection: None,
                 group_by: GroupByExpr::Expressions(vec![], vec![]),
@@ -2239,6 +2381,7 @@ fn parse_select_with_numeric_prefix_column_name() {
                         joins: vec![]
                     }],
                     lateral_views: vec![],
+                    array_joins: vec![],
                     prewhere: None,
                     selection: None,
                     group_by: GroupByExpr::Expressions(vec![], vec![]),
@@ -2414,6 +2557,7 @@ fn parse_select_with_concatenation_of_exp_number_and_numeric_prefix_column() {
                         joins: vec![]
                     }],
                     lateral_views: vec![],
+                    array_joins: vec![],
                     prewhere: None,
                     selection: None,
                     group_by: GroupByExpr::Expressions(vec![], vec![]),
@@ -2957,6 +3101,37 @@ fn parse_alter_table_with_lock() {
     mysql_and_generic().verified_stmt("ALTER TABLE `users` LOCK = EXCLUSIVE");
 }
 
+#[test]
+fn parse_alter_table_with_algorithm_and_lock() {
+    let sql = "ALTER TABLE t ADD COLUMN c INT, ALGORITHM = INPLACE, LOCK = NONE";
+    match mysql_and_generic().verified_stmt(sql) {
+        Statement::AlterTable { operations, .. } => {
+            assert_eq!(3, operations.len());
+            assert!(matches!(
+                operations[0],
+                AlterTableOperation::AddColumn { .. }
+            ));
+            assert_eq!(
+                operations[1..],
+                [
+                    AlterTableOperation::Algorithm {
+                        equals: true,
+                        algorithm: AlterTableAlgorithm::Inplace,
+                    },
+                    AlterTableOperation::Lock {
+                        equals: true,
+                        lock: AlterTableLock::None,
+                    },
+                ]
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    mysql_and_generic()
+        .verified_stmt("ALTER TABLE t MODIFY COLUMN c BIGINT, ALGORITHM INSTANT, LOCK DEFAULT");
+}
+
 #[test]
 fn parse_alter_table_auto_increment() {
     let sql = "ALTER TABLE tab AUTO_INCREMENT = 42";
@@ -3060,6 +3235,7 @@ fn parse_substring_in_select() {
                             joins: vec![]
                         }],
                         lateral_views: vec![],
+                        array_joins: vec![],
                         prewhere: None,
                         selection: None,
                         group_by: GroupByExpr::Expressions(vec![], vec![]),
@@ -3218,6 +3394,27 @@ fn parse_limit_my_sql_syntax() {
     mysql_and_generic().verified_stmt("SELECT id, fname, lname FROM customer LIMIT 10 OFFSET 5");
     mysql_and_generic().verified_stmt("SELECT id, fname, lname FROM customer LIMIT 5, 10");
     mysql_and_generic().verified_stmt("SELECT * FROM user LIMIT ? OFFSET ?");
+
+    // `LIMIT <offset>, <count>` keeps its comma form rather than becoming `OFFSET`
+    let query = mysql().verified_query("SELECT * FROM customer LIMIT 5, 10");
+    assert_eq!(
+        Some(LimitClause::OffsetCommaLimit {
+            offset: Expr::value(number("5")),
+            limit: Expr::value(number("10")),
+        }),
+        query.limit_clause
+    );
+    let query = mysql().verified_query("SELECT * FROM customer LIMIT ?, ?");
+    assert_eq!(
+        Some(LimitClause::OffsetCommaLimit {
+            offset: Expr::value(Value::Placeholder("?".into())),
+            limit: Expr::value(Value::Placeholder("?".into())),
+        }),
+        query.limit_clause
+    );
+    assert!(mysql()
+        .parse_sql_statements("SELECT * FROM customer LIMIT 5, 10 OFFSET 2")
+        .is_err());
 }
 
 #[test]
@@ -3367,6 +3564,7 @@ fn parse_hex_string_introducer() {
                 exclude: None,
                 from: vec![],
                 lateral_views: vec![],
+                array_joins: vec![],
                 prewhere: None,
                 selection: None,
                 group_by: GroupByExpr::Expressions(vec![], vec![]),
@@ -3622,6 +3820,7 @@ fn parse_grant() {
         objects,
         grantees,
         with_grant_option,
+        with_admin_option: _,
         as_grantor: _,
         granted_by,
         current_grants: _,
@@ -3779,6 +3978,15 @@ fn parse_create_view_security_param() {
     mysql().verified_stmt("CREATE SQL SECURITY INVOKER VIEW foo AS SELECT 1");
 }
 
+#[test]
+fn parse_create_view_with_check_option() {
+    mysql().verified_stmt("CREATE VIEW v AS SELECT a FROM t WITH CHECK OPTION");
+    mysql().verified_stmt("CREATE VIEW v AS SELECT a FROM t WITH LOCAL CHECK OPTION");
+    mysql().verified_stmt(
+        "CREATE ALGORITHM = MERGE VIEW v AS SELECT a FROM t WHERE a > 0 WITH CASCADED CHECK OPTION",
+    );
+}
+
 #[test]
 fn parse_create_view_multiple_params() {
     let sql = "CREATE ALGORITHM = UNDEFINED DEFINER = `root`@`%` SQL SECURITY INVOKER VIEW foo AS SELECT 1";
@@ -3886,7 +4094,7 @@ fn parse_create_trigger() {
                 exec_type: TriggerExecBodyType::Function,
                 func_desc: FunctionDesc {
                     name: ObjectName::from(vec![Ident::new("emp_stamp")]),
-                    args: None,
+                    args: Some(vec![]),
                 }
             }),
             statements: None,
@@ -3957,6 +4165,46 @@ fn parse_match_against_with_alias() {
     }
 }
 
+#[test]
+fn parse_match_against_search_modifiers() {
+    for (modifier, expected) in [
+        ("", None),
+        (
+            " IN NATURAL LANGUAGE MODE",
+            Some(SearchModifier::InNaturalLanguageMode),
+        ),
+        (
+            " IN NATURAL LANGUAGE MODE WITH QUERY EXPANSION",
+            Some(SearchModifier::InNaturalLanguageModeWithQueryExpansion),
+        ),
+        (" IN BOOLEAN MODE", Some(SearchModifier::InBooleanMode)),
+        (
+            " WITH QUERY EXPANSION",
+            Some(SearchModifier::WithQueryExpansion),
+        ),
+    ] {
+        let sql = format!("SELECT * FROM t WHERE MATCH (a, b) AGAINST ('x'{modifier})");
+        match mysql().verified_only_select(&sql).selection {
+            Some(Expr::MatchAgainst {
+                columns,
+                match_value,
+                opt_search_modifier,
+            }) => {
+                assert_eq!(
+                    columns,
+                    vec![
+                        ObjectName::from(vec![Ident::new("a")]),
+                        ObjectName::from(vec![Ident::new("b")]),
+                    ]
+                );
+                assert_eq!(match_value, Value::SingleQuotedString("x".to_owned()));
+                assert_eq!(opt_search_modifier, expected);
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+
 #[test]
 fn test_variable_assignment_using_colon_equal() {
     let sql_select = "SELECT @price := price, @tax := price * 0.1 FROM products WHERE id = 1";
@@ -4088,6 +4336,7 @@ fn parse_drop_index() {
             names,
             cascade,
             restrict,
+            cascade_constraints,
             purge,
             temporary,
             table,
@@ -4100,6 +4349,7 @@ fn parse_drop_index() {
             );
             assert!(!cascade);
             assert!(!restrict);
+            assert!(!cascade_constraints);
             assert!(!purge);
             assert!(!temporary);
             assert!(table.is_some());
@@ -4143,3 +4393,73 @@ fn parse_json_member_of() {
         _ => panic!("Unexpected statement {stmt}"),
     }
 }
+
+#[test]
+fn parse_interval_numeric_and_quoted_values() {
+    let interval = |value: Value| {
+        Expr::Interval(Interval {
+            value: Box::new(Expr::Value(value.with_empty_span())),
+            leading_field: Some(DateTimeField::Day),
+            leading_precision: None,
+            last_field: None,
+            fractional_seconds_precision: None,
+        })
+    };
+
+    let select = mysql().verified_only_select("SELECT INTERVAL 1 DAY, INTERVAL '1' DAY");
+    assert_eq!(
+        select.projection,
+        vec![
+            SelectItem::UnnamedExpr(interval(number("1"))),
+            SelectItem::UnnamedExpr(interval(Value::SingleQuotedString("1".to_string()))),
+        ]
+    );
+
+    let select = mysql().verified_only_select("SELECT NOW() + INTERVAL 1 DAY");
+    assert_eq!(
+        expr_from_projection(only(&select.projection)),
+        &Expr::BinaryOp {
+            left: Box::new(call("NOW", [])),
+            op: BinaryOperator::Plus,
+            right: Box::new(interval(number("1"))),
+        }
+    );
+
+    mysql().verified_stmt("SELECT DATE_ADD(d, INTERVAL 1 DAY)");
+    mysql().verified_stmt("SELECT DATE_SUB(d, INTERVAL '1' DAY)");
+    mysql().verified_stmt("SELECT d - INTERVAL 1 + 2 DAY");
+}
+
+#[test]
+fn parse_interval_compound_units() {
+    let select = mysql().verified_only_select("SELECT INTERVAL '2:30' HOUR_MINUTE");
+    assert_eq!(
+        expr_from_projection(only(&select.projection)),
+        &Expr::Interval(Interval {
+            value: Box::new(Expr::Value(
+                Value::SingleQuotedString("2:30".to_string()).with_empty_span()
+            )),
+            leading_field: Some(DateTimeField::HourMinute),
+            leading_precision: None,
+            last_field: None,
+            fractional_seconds_precision: None,
+        })
+    );
+
+    for unit in [
+        "SECOND_MICROSECOND",
+        "MINUTE_MICROSECOND",
+        "MINUTE_SECOND",
+        "HOUR_MICROSECOND",
+        "HOUR_SECOND",
+        "HOUR_MINUTE",
+        "DAY_MICROSECOND",
+        "DAY_SECOND",
+        "DAY_MINUTE",
+        "DAY_HOUR",
+        "YEAR_MONTH",
+    ] {
+        mysql_and_generic().verified_stmt(&format!("SELECT DATE_ADD(d, INTERVAL '1 2' {unit})"));
+    }
+    mysql().verified_stmt("SELECT EXTRACT(DAY_SECOND FROM d)");
+}
@@ -587,6 +587,48 @@ fn parse_create_table_constraints_only() {
     };
 }
 
+#[test]
+fn parse_create_table_like_with_options() {
+    let sql = "CREATE TABLE t (LIKE src INCLUDING DEFAULTS EXCLUDING CONSTRAINTS INCLUDING ALL)";
+    match pg_and_generic().verified_stmt(sql) {
+        Statement::CreateTable(CreateTable {
+            name,
+            columns,
+            like,
+            ..
+        }) => {
+            assert_eq!("t", name.to_string());
+            assert!(columns.is_empty());
+            assert_eq!(
+                like,
+                Some(CreateTableLikeKind::Parenthesized(CreateTableLike {
+                    name: ObjectName::from(vec![Ident::new("src")]),
+                    options: vec![
+                        CreateTableLikeOption::Including(CreateTableLikeProperty::Defaults),
+                        CreateTableLikeOption::Excluding(CreateTableLikeProperty::Constraints),
+                        CreateTableLikeOption::Including(CreateTableLikeProperty::All),
+                    ],
+                }))
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    pg_and_generic().verified_stmt("CREATE TABLE t (LIKE public.src)");
+    pg_and_generic().verified_stmt(
+        "CREATE TABLE t (LIKE src INCLUDING COMMENTS INCLUDING COMPRESSION INCLUDING GENERATED \
+         INCLUDING IDENTITY INCLUDING INDEXES INCLUDING STATISTICS EXCLUDING STORAGE)",
+    );
+
+    assert_eq!(
+        pg().parse_sql_statements("CREATE TABLE t (LIKE src INCLUDING)")
+            .unwrap_err(),
+        ParserError::ParserError(
+            "Expected: one of ALL or COMMENTS or COMPRESSION or CONSTRAINTS or DEFAULTS or GENERATED or IDENTITY or INDEXES or STATISTICS or STORAGE, found: )".to_string()
+        )
+    );
+}
+
 #[test]
 fn parse_alter_table_constraints_rename() {
     match alter_table_op(
@@ -600,6 +642,60 @@ fn parse_alter_table_constraints_rename() {
     }
 }
 
+#[test]
+fn parse_alter_table_attach_detach_partition() {
+    match alter_table_op(
+        pg().verified_stmt("ALTER TABLE tab ATTACH PARTITION p1 FOR VALUES FROM (1) TO (10)"),
+    ) {
+        AlterTableOperation::AttachTablePartition {
+            partition_name,
+            for_values,
+        } => {
+            assert_eq!("p1", partition_name.to_string());
+            assert_eq!(
+                ForValues::From {
+                    from: vec![PartitionBoundValue::Expr(Expr::value(number("1")))],
+                    to: vec![PartitionBoundValue::Expr(Expr::value(number("10")))],
+                },
+                for_values
+            );
+        }
+        _ => unreachable!(),
+    }
+    pg().verified_stmt("ALTER TABLE tab ATTACH PARTITION s.p2 FOR VALUES IN ('a', 'b')");
+    pg().verified_stmt(
+        "ALTER TABLE tab ATTACH PARTITION p3 FOR VALUES WITH (MODULUS 4, REMAINDER 0)",
+    );
+    pg().verified_stmt("ALTER TABLE tab ATTACH PARTITION p4 DEFAULT");
+
+    for (sql, expected_modifier) in [
+        ("ALTER TABLE tab DETACH PARTITION p1", None),
+        (
+            "ALTER TABLE tab DETACH PARTITION p1 CONCURRENTLY",
+            Some(DetachPartitionModifier::Concurrently),
+        ),
+        (
+            "ALTER TABLE tab DETACH PARTITION p1 FINALIZE",
+            Some(DetachPartitionModifier::Finalize),
+        ),
+    ] {
+        match alter_table_op(pg().verified_stmt(sql)) {
+            AlterTableOperation::DetachTablePartition {
+                partition_name,
+                modifier,
+            } => {
+                assert_eq!("p1", partition_name.to_string());
+                assert_eq!(expected_modifier, modifier);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    assert!(pg()
+        .parse_sql_statements("ALTER TABLE tab ATTACH PARTITION p1")
+        .is_err());
+}
+
 #[test]
 fn parse_alter_table_constraints_unique_nulls_distinct() {
     match pg_and_generic()
@@ -997,6 +1093,76 @@ fn parse_create_schema_if_not_exists() {
     }
 }
 
+#[test]
+fn parse_create_database_with_options() {
+    let sql = "CREATE DATABASE d WITH OWNER = u ENCODING = 'UTF8' TEMPLATE = template0";
+    match pg_and_generic().verified_stmt(sql) {
+        Statement::CreateDatabase {
+            db_name, options, ..
+        } => {
+            assert_eq!("d", db_name.to_string());
+            assert_eq!(
+                vec![
+                    CreateDatabaseOption::Owner(Ident::new("u")),
+                    CreateDatabaseOption::Encoding(Expr::value(Value::SingleQuotedString(
+                        "UTF8".to_string()
+                    ))),
+                    CreateDatabaseOption::Template(Ident::new("template0")),
+                ],
+                options
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    pg_and_generic().verified_stmt(
+        "CREATE DATABASE d WITH STRATEGY = wal_log LOCALE = 'C' LC_COLLATE = 'C' LC_CTYPE = 'C' \
+         TABLESPACE = ts ALLOW_CONNECTIONS = false CONNECTION LIMIT = -1 IS_TEMPLATE = true",
+    );
+    pg_and_generic().one_statement_parses_to(
+        "CREATE DATABASE d OWNER u ENCODING 'UTF8' CONNECTION LIMIT 10",
+        "CREATE DATABASE d WITH OWNER = u ENCODING = 'UTF8' CONNECTION LIMIT = 10",
+    );
+
+    assert_eq!(
+        pg().parse_sql_statements("CREATE DATABASE d WITH")
+            .unwrap_err()
+            .to_string(),
+        "sql parser error: Expected: CREATE DATABASE option after WITH, found: EOF"
+    );
+}
+
+#[test]
+fn parse_create_schema_authorization() {
+    match pg().verified_stmt("CREATE SCHEMA AUTHORIZATION joe") {
+        Statement::CreateSchema { schema_name, .. } => {
+            assert_eq!(
+                schema_name,
+                SchemaName::UnnamedAuthorization(Ident::new("joe"))
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    match pg().verified_stmt("CREATE SCHEMA IF NOT EXISTS hollywood AUTHORIZATION joe") {
+        Statement::CreateSchema {
+            schema_name,
+            if_not_exists,
+            ..
+        } => {
+            assert!(if_not_exists);
+            assert_eq!(
+                schema_name,
+                SchemaName::NamedAuthorization(
+                    ObjectName::from(vec![Ident::new("hollywood")]),
+                    Ident::new("joe")
+                )
+            );
+        }
+        _ => unreachable!(),
+    }
+}
+
 #[test]
 fn parse_drop_schema_if_exists() {
     let sql = "DROP SCHEMA IF EXISTS schema_name";
@@ -1036,6 +1202,34 @@ PHP	₱ USD $
     pg_and_generic().one_statement_parses_to(sql, "");
 }
 
+#[test]
+fn parse_copy_from_stdin_with_options() {
+    let stmt = pg().one_statement_parses_to(
+        "COPY t (a, b) FROM STDIN WITH (FORMAT csv, HEADER)",
+        "COPY t (a, b) FROM STDIN (FORMAT csv, HEADER)",
+    );
+    assert_eq!(
+        stmt,
+        Statement::Copy {
+            source: CopySource::Table {
+                table_name: ObjectName::from(vec!["t".into()]),
+                columns: vec!["a".into(), "b".into()],
+            },
+            to: false,
+            target: CopyTarget::Stdin,
+            options: vec![CopyOption::Format("csv".into()), CopyOption::Header(true),],
+            legacy_options: vec![],
+            values: vec![],
+        }
+    );
+
+    pg().verified_stmt("COPY t TO STDOUT (FORMAT csv, DELIMITER '|')");
+    pg().one_statement_parses_to(
+        "COPY t FROM PROGRAM 'gunzip -c f.gz' WITH (FORMAT csv, HEADER true)",
+        "COPY t FROM PROGRAM 'gunzip -c f.gz' (FORMAT csv, HEADER)",
+    );
+}
+
 #[test]
 fn test_copy_from() {
     let stmt = pg().verified_stmt("COPY users FROM 'data.csv'");
@@ -1309,6 +1503,7 @@ fn parse_copy_to() {
                     into: None,
                     from: vec![],
                     lateral_views: vec![],
+                    array_joins: vec![],
                     prewhere: None,
                     selection: None,
                     group_by: GroupByExpr::Expressions(vec![], vec![]),
@@ -1597,6 +1792,55 @@ fn parse_set_role() {
     assert_eq!(query, stmt.to_string());
 }
 
+#[test]
+fn parse_set_session_authorization() {
+    assert_eq!(
+        pg_and_generic().verified_stmt("SET SESSION AUTHORIZATION DEFAULT"),
+        Statement::Set(Set::SetSessionAuthorization {
+            context_modifier: None,
+            user_name: None,
+        })
+    );
+    assert_eq!(
+        pg_and_generic().verified_stmt("SET SESSION AUTHORIZATION alice"),
+        Statement::Set(Set::SetSessionAuthorization {
+            context_modifier: None,
+            user_name: Some(Ident::new("alice")),
+        })
+    );
+    assert_eq!(
+        pg_and_generic().verified_stmt("SET LOCAL SESSION AUTHORIZATION alice"),
+        Statement::Set(Set::SetSessionAuthorization {
+            context_modifier: Some(ContextModifier::Local),
+            user_name: Some(Ident::new("alice")),
+        })
+    );
+    pg_and_generic().verified_stmt("SET SESSION SESSION AUTHORIZATION 'alice'");
+}
+
+#[test]
+fn parse_reset() {
+    assert_eq!(
+        pg_and_generic().verified_stmt("RESET ALL"),
+        Statement::Reset(Reset::All)
+    );
+    assert_eq!(
+        pg_and_generic().verified_stmt("RESET ROLE"),
+        Statement::Reset(Reset::Role)
+    );
+    assert_eq!(
+        pg_and_generic().verified_stmt("RESET SESSION AUTHORIZATION"),
+        Statement::Reset(Reset::SessionAuthorization)
+    );
+    assert_eq!(
+        pg_and_generic().verified_stmt("RESET search_path"),
+        Statement::Reset(Reset::ConfigurationParameter(ObjectName::from(vec![
+            Ident::new("search_path")
+        ])))
+    );
+    pg_and_generic().verified_stmt("RESET my_ext.setting");
+}
+
 #[test]
 fn parse_show() {
     let stmt = pg_and_generic().verified_stmt("SHOW a a");
@@ -2613,6 +2857,7 @@ fn parse_create_indices_with_operator_classes() {
                             clauses: vec![],
                         }),
                         filter: None,
+                        from_first: None,
                         null_treatment: None,
                         over: None,
                         within_group: vec![],
@@ -2965,6 +3210,51 @@ fn parse_create_index_with_nulls_distinct() {
     }
 }
 
+#[test]
+fn parse_typed_array_literals() {
+    let select = pg().verified_only_select("SELECT ARRAY[]::TEXT[]");
+    assert_eq!(
+        &Expr::Cast {
+            kind: CastKind::DoubleColon,
+            expr: Box::new(Expr::Array(Array {
+                elem: vec![],
+                named: true,
+            })),
+            data_type: DataType::Array(ArrayElemTypeDef::SquareBracket(
+                Box::new(DataType::Text),
+                None
+            )),
+            format: None,
+        },
+        expr_from_projection(only(&select.projection))
+    );
+
+    let select = pg().verified_only_select("SELECT ARRAY[1, 2, 3]::INT[]");
+    assert_eq!(
+        &Expr::Cast {
+            kind: CastKind::DoubleColon,
+            expr: Box::new(Expr::Array(Array {
+                elem: vec![
+                    Expr::value(number("1")),
+                    Expr::value(number("2")),
+                    Expr::value(number("3")),
+                ],
+                named: true,
+            })),
+            data_type: DataType::Array(ArrayElemTypeDef::SquareBracket(
+                Box::new(DataType::Int(None)),
+                None
+            )),
+            format: None,
+        },
+        expr_from_projection(only(&select.projection))
+    );
+
+    pg().verified_stmt("SELECT CAST(ARRAY[] AS INTEGER[][])");
+    pg().verified_stmt("SELECT ARRAY[ARRAY[1], ARRAY[2]]::INT[][]");
+    pg().one_statement_parses_to("SELECT ARRAY[]::text[]", "SELECT ARRAY[]::TEXT[]");
+}
+
 #[test]
 fn parse_array_subquery_expr() {
     let sql = "SELECT ARRAY(SELECT 1 UNION SELECT 2)";
@@ -2991,6 +3281,7 @@ fn parse_array_subquery_expr() {
                         into: None,
                         from: vec![],
                         lateral_views: vec![],
+                        array_joins: vec![],
                         prewhere: None,
                         selection: None,
                         group_by: GroupByExpr::Expressions(vec![], vec![]),
@@ -3017,6 +3308,7 @@ fn parse_array_subquery_expr() {
                         into: None,
                         from: vec![],
                         lateral_views: vec![],
+                        array_joins: vec![],
                         prewhere: None,
                         selection: None,
                         group_by: GroupByExpr::Expressions(vec![], vec![]),
@@ -3042,6 +3334,7 @@ fn parse_array_subquery_expr() {
                 pipe_operators: vec![],
             })),
             filter: None,
+            from_first: None,
             null_treatment: None,
             over: None,
             within_group: vec![]
@@ -3319,6 +3612,53 @@ fn test_fn_arg_with_value_operator() {
     }
 }
 
+#[test]
+fn test_json_arrow_operator_chain() {
+    let sql = "SELECT a -> 'b' ->> 'c', a #> '{b}' #>> '{c}' FROM t";
+    let select = pg().verified_only_select(sql);
+    assert_eq!(
+        &Expr::BinaryOp {
+            left: Box::new(Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Ident::new("a"))),
+                op: BinaryOperator::Arrow,
+                right: Box::new(Expr::value(Value::SingleQuotedString("b".to_string()))),
+            }),
+            op: BinaryOperator::LongArrow,
+            right: Box::new(Expr::value(Value::SingleQuotedString("c".to_string()))),
+        },
+        expr_from_projection(&select.projection[0])
+    );
+    assert_eq!(
+        &Expr::BinaryOp {
+            left: Box::new(Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Ident::new("a"))),
+                op: BinaryOperator::HashArrow,
+                right: Box::new(Expr::value(Value::SingleQuotedString("{b}".to_string()))),
+            }),
+            op: BinaryOperator::HashLongArrow,
+            right: Box::new(Expr::value(Value::SingleQuotedString("{c}".to_string()))),
+        },
+        expr_from_projection(&select.projection[1])
+    );
+
+    // each operator re-serializes as written, regardless of whitespace
+    pg().one_statement_parses_to(
+        "SELECT a->'b'->>'c', a#>'{b}'->'c', a->'b'#>>'{c}' FROM t",
+        "SELECT a -> 'b' ->> 'c', a #> '{b}' -> 'c', a -> 'b' #>> '{c}' FROM t",
+    );
+
+    // the arrow chain binds tighter than comparison
+    let select = pg().verified_only_select("SELECT * FROM t WHERE a -> 'b' ->> 'c' = 'x'");
+    match select.selection {
+        Some(Expr::BinaryOp {
+            left,
+            op: BinaryOperator::Eq,
+            ..
+        }) => assert_eq!("a -> 'b' ->> 'c'", left.to_string()),
+        other => panic!("unexpected selection: {other:?}"),
+    }
+}
+
 #[test]
 fn parse_json_table_is_not_reserved() {
     // JSON_TABLE is not a reserved keyword in PostgreSQL, even though it is in SQL:2023
@@ -3403,6 +3743,7 @@ fn test_composite_value() {
                     )))],
                     clauses: vec![],
                 }),
+                from_first: None,
                 null_treatment: None,
                 filter: None,
                 over: None,
@@ -3570,6 +3911,7 @@ fn parse_current_functions() {
             uses_odbc_syntax: false,
             parameters: FunctionArguments::None,
             args: FunctionArguments::None,
+            from_first: None,
             null_treatment: None,
             filter: None,
             over: None,
@@ -3583,6 +3925,7 @@ fn parse_current_functions() {
             uses_odbc_syntax: false,
             parameters: FunctionArguments::None,
             args: FunctionArguments::None,
+            from_first: None,
             null_treatment: None,
             filter: None,
             over: None,
@@ -3596,6 +3939,7 @@ fn parse_current_functions() {
             uses_odbc_syntax: false,
             parameters: FunctionArguments::None,
             args: FunctionArguments::None,
+            from_first: None,
             null_treatment: None,
             filter: None,
             over: None,
@@ -3609,6 +3953,7 @@ fn parse_current_functions() {
             uses_odbc_syntax: false,
             parameters: FunctionArguments::None,
             args: FunctionArguments::None,
+            from_first: None,
             null_treatment: None,
             filter: None,
             over: None,
@@ -4104,6 +4449,7 @@ fn parse_delimited_identifiers() {
                 args: vec![],
                 clauses: vec![],
             }),
+            from_first: None,
             null_treatment: None,
             filter: None,
             over: None,
@@ -4493,6 +4839,31 @@ fn parse_drop_function() {
             drop_behavior: None
         }
     );
+
+    // overloads are disambiguated by their argument types
+    let sql = "DROP FUNCTION f(INT, TEXT), f(INT) RESTRICT";
+    assert_eq!(
+        pg().verified_stmt(sql),
+        Statement::DropFunction {
+            if_exists: false,
+            func_desc: vec![
+                FunctionDesc {
+                    name: ObjectName::from(vec![Ident::new("f")]),
+                    args: Some(vec![
+                        OperateFunctionArg::unnamed(DataType::Int(None)),
+                        OperateFunctionArg::unnamed(DataType::Text),
+                    ]),
+                },
+                FunctionDesc {
+                    name: ObjectName::from(vec![Ident::new("f")]),
+                    args: Some(vec![OperateFunctionArg::unnamed(DataType::Int(None))]),
+                },
+            ],
+            drop_behavior: Some(DropBehavior::Restrict),
+        }
+    );
+
+    pg().verified_stmt("DROP FUNCTION f()");
 }
 
 #[test]
@@ -4555,6 +4926,30 @@ fn parse_drop_domain() {
     );
 }
 
+#[test]
+fn parse_create_procedure_with_parameter_modes() {
+    let sql = "CREATE PROCEDURE p (OUT total BIGINT, a INT, IN b TEXT, INOUT c INT) AS BEGIN SELECT 1; END";
+    match pg().verified_stmt(sql) {
+        Statement::CreateProcedure { params, .. } => {
+            let modes = params
+                .unwrap()
+                .into_iter()
+                .map(|p| (p.name.value, p.mode))
+                .collect::<Vec<_>>();
+            assert_eq!(
+                vec![
+                    ("total".to_string(), Some(ArgMode::Out)),
+                    ("a".to_string(), None),
+                    ("b".to_string(), Some(ArgMode::In)),
+                    ("c".to_string(), Some(ArgMode::InOut)),
+                ],
+                modes
+            );
+        }
+        _ => unreachable!(),
+    }
+}
+
 #[test]
 fn parse_drop_procedure() {
     let sql = "DROP PROCEDURE IF EXISTS test_proc";
@@ -4761,6 +5156,34 @@ fn parse_incorrect_dollar_quoted_string() {
     assert!(pg().parse_sql_statements(sql).is_err());
 }
 
+#[test]
+fn parse_numbered_placeholders_with_dollar_quoted_strings() {
+    let sql = "SELECT $1, $$text$$, $tag$x $1 y$tag$ FROM t WHERE a = $2";
+    let select = pg().verified_only_select(sql);
+    assert_eq!(
+        vec![
+            SelectItem::UnnamedExpr(Expr::value(Value::Placeholder("$1".into()))),
+            SelectItem::UnnamedExpr(Expr::value(Value::DollarQuotedString(DollarQuotedString {
+                value: "text".into(),
+                tag: None,
+            }))),
+            SelectItem::UnnamedExpr(Expr::value(Value::DollarQuotedString(DollarQuotedString {
+                value: "x $1 y".into(),
+                tag: Some("tag".into()),
+            }))),
+        ],
+        select.projection
+    );
+    assert_eq!(
+        Some(Expr::BinaryOp {
+            left: Box::new(Expr::Identifier(Ident::new("a"))),
+            op: BinaryOperator::Eq,
+            right: Box::new(Expr::value(Value::Placeholder("$2".into()))),
+        }),
+        select.selection
+    );
+}
+
 #[test]
 fn parse_select_group_by_grouping_sets() {
     let select = pg_and_generic().verified_only_select(
@@ -4973,7 +5396,7 @@ fn parse_create_table_with_alias() {
 
 #[test]
 fn parse_create_table_with_partition_by() {
-    let sql = "CREATE TABLE t1 (a INT, b TEXT) PARTITION BY RANGE(a)";
+    let sql = "CREATE TABLE t1 (a INT, b TEXT) PARTITION BY RANGE (a)";
     match pg_and_generic().verified_stmt(sql) {
         Statement::CreateTable(create_table) => {
             assert_eq!("t1", create_table.name.to_string());
@@ -4992,34 +5415,203 @@ fn parse_create_table_with_partition_by() {
                 ],
                 create_table.columns
             );
-            match *create_table.partition_by.unwrap() {
-                Expr::Function(f) => {
-                    assert_eq!("RANGE", f.name.to_string());
-                    assert_eq!(
-                        FunctionArguments::List(FunctionArgumentList {
-                            duplicate_treatment: None,
-                            clauses: vec![],
-                            args: vec![FunctionArg::Unnamed(FunctionArgExpr::Expr(
-                                Expr::Identifier(Ident::new("a"))
-                            ))],
-                        }),
-                        f.args
-                    );
-                }
-                _ => unreachable!(),
-            }
+            assert_eq!(create_table.partition_by, None);
+            assert_eq!(
+                create_table.partition_strategy,
+                Some(PartitionStrategy::Range(vec![Expr::Identifier(
+                    Ident::new("a")
+                )]))
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    pg_and_generic().one_statement_parses_to(
+        "CREATE TABLE t1 (a INT, b TEXT) PARTITION BY RANGE(a)",
+        "CREATE TABLE t1 (a INT, b TEXT) PARTITION BY RANGE (a)",
+    );
+    pg_and_generic().verified_stmt("CREATE TABLE t1 (a INT, b TEXT) PARTITION BY LIST (b)");
+    pg_and_generic()
+        .verified_stmt("CREATE TABLE t1 (a INT, b TEXT) PARTITION BY HASH (a, (lower(b)))");
+
+    // a partition expression that isn't a strategy is kept as an expression
+    match pg_and_generic().verified_stmt("CREATE TABLE t1 (range INT) PARTITION BY range") {
+        Statement::CreateTable(create_table) => {
+            assert_eq!(create_table.partition_strategy, None);
+            assert_eq!(
+                create_table.partition_by,
+                Some(Box::new(Expr::Identifier(Ident::new("range"))))
+            );
         }
         _ => unreachable!(),
     }
 }
 
 #[test]
-fn parse_join_constraint_unnest_alias() {
-    assert_eq!(
-        only(
-            pg().verified_only_select("SELECT * FROM t1 JOIN UNNEST(t1.a) AS f ON c1 = c2")
-                .from
-        )
+fn parse_create_table_partition_of() {
+    let sql = "CREATE TABLE c PARTITION OF p FOR VALUES FROM (1) TO (10)";
+    match pg_and_generic().verified_stmt(sql) {
+        Statement::CreateTable(create_table) => {
+            assert_eq!("c", create_table.name.to_string());
+            assert!(create_table.columns.is_empty());
+            assert_eq!(
+                create_table.partition_of,
+                Some(ObjectName::from(vec![Ident::new("p")]))
+            );
+            assert_eq!(
+                create_table.for_values,
+                Some(ForValues::From {
+                    from: vec![PartitionBoundValue::Expr(Expr::value(number("1")))],
+                    to: vec![PartitionBoundValue::Expr(Expr::value(number("10")))],
+                })
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    match pg_and_generic()
+        .verified_stmt("CREATE TABLE c PARTITION OF p FOR VALUES WITH (MODULUS 4, REMAINDER 3)")
+    {
+        Statement::CreateTable(create_table) => {
+            assert_eq!(
+                create_table.for_values,
+                Some(ForValues::With {
+                    modulus: 4,
+                    remainder: 3
+                })
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    pg_and_generic().verified_stmt(
+        "CREATE TABLE c PARTITION OF p FOR VALUES FROM (MINVALUE, 1) TO ('2020-01-01', MAXVALUE)",
+    );
+    pg_and_generic().verified_stmt("CREATE TABLE c PARTITION OF p FOR VALUES IN ('a', 'b')");
+    pg_and_generic().verified_stmt(
+        "CREATE TABLE c PARTITION OF p FOR VALUES IN ('a') PARTITION BY RANGE (created_at)",
+    );
+    pg_and_generic().verified_stmt("CREATE TABLE IF NOT EXISTS s.c PARTITION OF s.p DEFAULT");
+
+    assert_eq!(
+        pg().parse_sql_statements("CREATE TABLE c PARTITION OF p FOR VALUES (1)")
+            .unwrap_err()
+            .to_string(),
+        "sql parser error: Expected: IN, FROM or WITH after FOR VALUES, found: ("
+    );
+}
+
+#[test]
+fn parse_create_table_partition_of_with_columns() {
+    let sql =
+        "CREATE TABLE c PARTITION OF p (a NOT NULL, CHECK (a > 0)) FOR VALUES FROM (1) TO (10)";
+    match pg_and_generic().verified_stmt(sql) {
+        Statement::CreateTable(create_table) => {
+            assert_eq!(
+                create_table.columns,
+                vec![ColumnDef {
+                    name: Ident::new("a"),
+                    data_type: DataType::Unspecified,
+                    options: vec![ColumnOptionDef {
+                        name: None,
+                        option: ColumnOption::NotNull,
+                    }],
+                }]
+            );
+            assert_eq!(only(create_table.constraints).to_string(), "CHECK (a > 0)");
+            assert_eq!(
+                create_table.partition_of,
+                Some(ObjectName::from(vec![Ident::new("p")]))
+            );
+            assert!(create_table.for_values.is_some());
+        }
+        _ => unreachable!(),
+    }
+
+    pg_and_generic().verified_stmt(
+        "CREATE TABLE c PARTITION OF p (a DEFAULT 0, CONSTRAINT pos CHECK (a > 0)) DEFAULT",
+    );
+    match pg_and_generic().verified_stmt("CREATE TABLE c PARTITION OF p DEFAULT") {
+        Statement::CreateTable(create_table) => {
+            assert!(create_table.columns.is_empty());
+            assert!(create_table.constraints.is_empty());
+            assert_eq!(create_table.for_values, Some(ForValues::Default));
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_collate_expr() {
+    let query = pg().verified_query("SELECT * FROM t ORDER BY name COLLATE \"de_DE\" DESC");
+    let order_by = query.order_by.unwrap();
+    assert_eq!(
+        order_by.kind,
+        OrderByKind::Expressions(vec![OrderByExpr {
+            expr: Expr::Collate {
+                expr: Box::new(Expr::Identifier(Ident::new("name"))),
+                collation: ObjectName::from(vec![Ident::with_quote('"', "de_DE")]),
+            },
+            options: OrderByOptions {
+                asc: Some(false),
+                nulls_first: None,
+            },
+            with_fill: None,
+        }])
+    );
+
+    // unquoted and qualified collation names
+    assert_eq!(
+        pg().verified_expr("name COLLATE pg_catalog.default"),
+        Expr::Collate {
+            expr: Box::new(Expr::Identifier(Ident::new("name"))),
+            collation: ObjectName::from(vec![Ident::new("pg_catalog"), Ident::new("default")]),
+        }
+    );
+
+    pg().verified_expr("(a || b) COLLATE \"C\"");
+    pg().verified_stmt("SELECT a COLLATE \"C\" < b COLLATE \"C\" FROM t");
+    pg().verified_stmt("SELECT * FROM t WHERE lower(name) COLLATE \"en_US\" = 'x'");
+}
+
+#[test]
+fn parse_create_view_with_check_option() {
+    let sql = "CREATE VIEW v AS SELECT a FROM t WHERE a > 0 WITH CHECK OPTION";
+    match pg_and_generic().verified_stmt(sql) {
+        Statement::CreateView {
+            with_check_option, ..
+        } => assert_eq!(with_check_option, Some(CheckOption::Default)),
+        _ => unreachable!(),
+    }
+
+    let sql = "CREATE VIEW v AS SELECT a FROM t WITH LOCAL CHECK OPTION";
+    match pg_and_generic().verified_stmt(sql) {
+        Statement::CreateView {
+            with_check_option, ..
+        } => assert_eq!(with_check_option, Some(CheckOption::Local)),
+        _ => unreachable!(),
+    }
+
+    let sql = "CREATE OR REPLACE VIEW v (a) AS SELECT a FROM t WITH CASCADED CHECK OPTION";
+    match pg_and_generic().verified_stmt(sql) {
+        Statement::CreateView {
+            with_check_option, ..
+        } => assert_eq!(with_check_option, Some(CheckOption::Cascaded)),
+        _ => unreachable!(),
+    }
+
+    assert!(pg()
+        .parse_sql_statements("CREATE VIEW v AS SELECT a FROM t WITH GLOBAL CHECK OPTION")
+        .is_err());
+}
+
+#[test]
+fn parse_join_constraint_unnest_alias() {
+    assert_eq!(
+        only(
+            pg().verified_only_select("SELECT * FROM t1 JOIN UNNEST(t1.a) AS f ON c1 = c2")
+                .from
+        )
         .joins,
         vec![Join {
             relation: TableFactor::UNNEST {
@@ -5286,6 +5878,24 @@ fn parse_mat_cte() {
 
     let sql2 = r#"WITH cte AS NOT MATERIALIZED (SELECT id FROM accounts) SELECT id FROM cte"#;
     pg().verified_stmt(sql2);
+
+    let sql = "WITH a AS MATERIALIZED (SELECT 1), b (x) AS NOT MATERIALIZED (SELECT 2), c AS (SELECT 3) SELECT * FROM a";
+    let query = pg().verified_query(sql);
+    let materialized: Vec<_> = query
+        .with
+        .unwrap()
+        .cte_tables
+        .into_iter()
+        .map(|cte| cte.materialized)
+        .collect();
+    assert_eq!(
+        materialized,
+        vec![
+            Some(CteAsMaterialized::Materialized),
+            Some(CteAsMaterialized::NotMaterialized),
+            None,
+        ]
+    );
 }
 
 #[test]
@@ -5571,6 +6181,50 @@ fn parse_create_after_update_trigger_with_condition() {
     assert_eq!(pg().verified_stmt(sql), expected);
 }
 
+#[test]
+fn parse_create_trigger_update_of_columns() {
+    let sql = "CREATE TRIGGER trg AFTER UPDATE OF a, b OR DELETE ON t FOR EACH STATEMENT WHEN (OLD.a IS DISTINCT FROM NEW.a) EXECUTE FUNCTION f()";
+    let expected = Statement::CreateTrigger {
+        or_alter: false,
+        or_replace: false,
+        is_constraint: false,
+        name: ObjectName::from(vec![Ident::new("trg")]),
+        period: TriggerPeriod::After,
+        events: vec![
+            TriggerEvent::Update(vec![Ident::new("a"), Ident::new("b")]),
+            TriggerEvent::Delete,
+        ],
+        table_name: ObjectName::from(vec![Ident::new("t")]),
+        referenced_table_name: None,
+        referencing: vec![],
+        trigger_object: TriggerObject::Statement,
+        include_each: true,
+        condition: Some(Expr::Nested(Box::new(Expr::IsDistinctFrom(
+            Box::new(Expr::CompoundIdentifier(vec![
+                Ident::new("OLD"),
+                Ident::new("a"),
+            ])),
+            Box::new(Expr::CompoundIdentifier(vec![
+                Ident::new("NEW"),
+                Ident::new("a"),
+            ])),
+        )))),
+        exec_body: Some(TriggerExecBody {
+            exec_type: TriggerExecBodyType::Function,
+            func_desc: FunctionDesc {
+                name: ObjectName::from(vec![Ident::new("f")]),
+                args: Some(vec![]),
+            },
+        }),
+        statements: None,
+        characteristics: None,
+    };
+
+    assert_eq!(pg().verified_stmt(sql), expected);
+
+    pg().verified_stmt("CREATE TRIGGER trg BEFORE INSERT ON t FOR EACH ROW EXECUTE FUNCTION f()");
+}
+
 #[test]
 fn parse_create_instead_of_delete_trigger() {
     let sql = "CREATE TRIGGER check_delete INSTEAD OF DELETE ON accounts FOR EACH ROW EXECUTE FUNCTION check_account_deletes";
@@ -5867,6 +6521,8 @@ fn parse_trigger_related_functions() {
                 },
             ],
             constraints: vec![],
+            period_for_system_time: None,
+            with_system_versioning: false,
             hive_distribution: HiveDistributionStyle::NONE,
             hive_formats: Some(HiveFormat {
                 row_format: None,
@@ -5886,6 +6542,9 @@ fn parse_trigger_related_functions() {
             primary_key: None,
             order_by: None,
             partition_by: None,
+            partition_strategy: None,
+            partition_of: None,
+            for_values: None,
             cluster_by: None,
             clustered_by: None,
             inherits: None,
@@ -5966,7 +6625,7 @@ fn parse_trigger_related_functions() {
                 exec_type: TriggerExecBodyType::Function,
                 func_desc: FunctionDesc {
                     name: ObjectName::from(vec![Ident::new("emp_stamp")]),
-                    args: None,
+                    args: Some(vec![]),
                 }
             }),
             statements: None,
@@ -6168,6 +6827,25 @@ fn parse_alter_type() {
                 position: None,
             }),
         },
+        TestCase {
+            sql: "ALTER TYPE mood ADD VALUE 'excited' BEFORE 'happy'",
+            name: "mood",
+            operation: AlterTypeOperation::AddValue(AlterTypeAddValue {
+                if_not_exists: false,
+                value: Ident::with_quote('\'', "excited"),
+                position: Some(AlterTypeAddValuePosition::Before(Ident::with_quote(
+                    '\'', "happy",
+                ))),
+            }),
+        },
+        TestCase {
+            sql: "ALTER TYPE mood RENAME VALUE 'sad' TO 'unhappy'",
+            name: "mood",
+            operation: AlterTypeOperation::RenameValue(AlterTypeRenameValue {
+                from: Ident::with_quote('\'', "sad"),
+                to: Ident::with_quote('\'', "unhappy"),
+            }),
+        },
     ]
     .into_iter()
     .enumerate()
@@ -6182,6 +6860,16 @@ fn parse_alter_type() {
     });
 }
 
+#[test]
+fn parse_alter_type_add_value_missing_position_value() {
+    assert_eq!(
+        pg().parse_sql_statements("ALTER TYPE mood ADD VALUE 'excited' BEFORE"),
+        Err(ParserError::ParserError(
+            "Expected: identifier, found: EOF".to_string()
+        ))
+    );
+}
+
 #[test]
 fn parse_bitstring_literal() {
     let select = pg_and_generic().verified_only_select("SELECT B'111'");
@@ -6313,6 +7001,25 @@ fn parse_alter_table_validate_constraint() {
         }
         _ => unreachable!(),
     }
+
+    match pg().verified_stmt(
+        "ALTER TABLE foo ADD CONSTRAINT c CHECK (a > 0) NOT VALID, VALIDATE CONSTRAINT bar",
+    ) {
+        Statement::AlterTable { operations, .. } => {
+            assert!(matches!(
+                &operations[0],
+                AlterTableOperation::AddConstraint {
+                    constraint: TableConstraint::Check { .. },
+                    not_valid: true,
+                }
+            ));
+            assert_eq!(
+                operations[1],
+                AlterTableOperation::ValidateConstraint { name: "bar".into() }
+            );
+        }
+        _ => unreachable!(),
+    }
 }
 
 #[test]
@@ -6393,3 +7100,730 @@ fn parse_create_server() {
         assert_eq!(stmt, expected);
     }
 }
+
+#[test]
+fn parse_named_window_inheritance() {
+    let sql = "SELECT SUM(x) OVER w2 FROM t WINDOW w1 AS (PARTITION BY a), w2 AS (w1 ORDER BY b ROWS UNBOUNDED PRECEDING)";
+    let select = pg().verified_only_select(sql);
+    assert_eq!(
+        vec![
+            NamedWindowDefinition(
+                Ident::new("w1"),
+                NamedWindowExpr::WindowSpec(WindowSpec {
+                    window_name: None,
+                    partition_by: vec![Expr::Identifier(Ident::new("a"))],
+                    order_by: vec![],
+                    window_frame: None,
+                }),
+            ),
+            NamedWindowDefinition(
+                Ident::new("w2"),
+                NamedWindowExpr::WindowSpec(WindowSpec {
+                    window_name: Some(Ident::new("w1")),
+                    partition_by: vec![],
+                    order_by: vec![OrderByExpr {
+                        expr: Expr::Identifier(Ident::new("b")),
+                        options: OrderByOptions {
+                            asc: None,
+                            nulls_first: None,
+                        },
+                        with_fill: None,
+                    }],
+                    window_frame: Some(WindowFrame {
+                        units: WindowFrameUnits::Rows,
+                        start_bound: WindowFrameBound::Preceding(None),
+                        end_bound: None,
+                        exclusion: None,
+                    }),
+                }),
+            ),
+        ],
+        select.named_window
+    );
+
+    // an inherited window may also be referenced directly in OVER
+    pg().verified_stmt(
+        "SELECT SUM(x) OVER (w1 ORDER BY b), AVG(x) OVER w1 FROM t WINDOW w1 AS (PARTITION BY a)",
+    );
+}
+
+#[test]
+fn parse_overlay() {
+    let select = pg().verified_only_select(
+        "SELECT OVERLAY('Txxxxas' PLACING 'hom' FROM 2 FOR 4), OVERLAY(name PLACING 'x' FROM pos) FROM t",
+    );
+    assert_eq!(
+        &Expr::Overlay {
+            expr: Box::new(Expr::value(Value::SingleQuotedString(
+                "Txxxxas".to_string()
+            ))),
+            overlay_what: Box::new(Expr::value(Value::SingleQuotedString("hom".to_string()))),
+            overlay_from: Box::new(Expr::value(number("2"))),
+            overlay_for: Some(Box::new(Expr::value(number("4")))),
+        },
+        expr_from_projection(&select.projection[0])
+    );
+    assert_eq!(
+        &Expr::Overlay {
+            expr: Box::new(Expr::Identifier(Ident::new("name"))),
+            overlay_what: Box::new(Expr::value(Value::SingleQuotedString("x".to_string()))),
+            overlay_from: Box::new(Expr::Identifier(Ident::new("pos"))),
+            overlay_for: None,
+        },
+        expr_from_projection(&select.projection[1])
+    );
+}
+
+#[test]
+fn parse_merge_returning() {
+    let sql = "MERGE INTO t USING s ON t.id = s.id WHEN MATCHED THEN DELETE WHEN NOT MATCHED THEN INSERT (id) VALUES (s.id) RETURNING merge_action(), t.*";
+    match pg_and_generic().verified_stmt(sql) {
+        Statement::Merge { returning, .. } => {
+            assert_eq!(
+                Some(vec![
+                    SelectItem::UnnamedExpr(call("merge_action", [])),
+                    SelectItem::QualifiedWildcard(
+                        SelectItemQualifiedWildcardKind::ObjectName(ObjectName::from(vec![
+                            Ident::new("t")
+                        ])),
+                        WildcardAdditionalOptions::default()
+                    ),
+                ]),
+                returning
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    pg_and_generic().verified_stmt(
+        "MERGE INTO t USING s ON t.id = s.id WHEN MATCHED THEN UPDATE SET v = s.v RETURNING merge_action() AS action, t.id",
+    );
+}
+
+#[test]
+fn parse_update_from_with_joins() {
+    let sql = "UPDATE t SET a = b.x FROM other AS b JOIN third AS c ON b.id = c.id, fourth WHERE t.id = b.id";
+    match pg().verified_stmt(sql) {
+        Statement::Update {
+            table,
+            from: Some(UpdateTableFromKind::AfterSet(from)),
+            selection,
+            ..
+        } => {
+            assert_eq!("t", table.to_string());
+            assert_eq!(2, from.len());
+            assert_eq!(
+                TableWithJoins {
+                    relation: table_with_alias("other", "b"),
+                    joins: vec![Join {
+                        relation: table_with_alias("third", "c"),
+                        global: false,
+                        join_operator: JoinOperator::Join(JoinConstraint::On(Expr::BinaryOp {
+                            left: Box::new(Expr::CompoundIdentifier(vec![
+                                Ident::new("b"),
+                                Ident::new("id"),
+                            ])),
+                            op: BinaryOperator::Eq,
+                            right: Box::new(Expr::CompoundIdentifier(vec![
+                                Ident::new("c"),
+                                Ident::new("id"),
+                            ])),
+                        })),
+                    }],
+                },
+                from[0]
+            );
+            assert_eq!("fourth", from[1].to_string());
+            assert_eq!(
+                Some("t.id = b.id".to_string()),
+                selection.map(|s| s.to_string())
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    pg().verified_stmt(
+        "UPDATE t SET a = b.x FROM other AS b LEFT JOIN third AS c ON b.id = c.id WHERE t.id = b.id RETURNING t.a",
+    );
+}
+
+#[test]
+fn parse_create_user() {
+    match pg().verified_stmt("CREATE USER u WITH LOGIN PASSWORD 'x'") {
+        Statement::CreateUser(stmt) => {
+            assert_eq!(stmt.name, Ident::new("u"));
+            assert_eq!(
+                stmt.role_options,
+                vec![
+                    RoleOption::Login(true),
+                    RoleOption::Password(Password::Password(Expr::Value(
+                        (Value::SingleQuotedString("x".into())).with_empty_span()
+                    ))),
+                ]
+            );
+            assert!(stmt.options.options.is_empty());
+        }
+        _ => unreachable!(),
+    }
+
+    pg().verified_stmt("CREATE USER u");
+    pg().verified_stmt("CREATE USER u WITH NOSUPERUSER CREATEDB CONNECTION LIMIT 5");
+    pg().one_statement_parses_to(
+        "CREATE USER u LOGIN VALID UNTIL '2030-01-01'",
+        "CREATE USER u WITH LOGIN VALID UNTIL '2030-01-01'",
+    );
+
+    assert_eq!(
+        pg().parse_sql_statements("CREATE USER u WITH").unwrap_err(),
+        ParserError::ParserError("Expected: role option after WITH, found: EOF".to_string())
+    );
+}
+
+#[test]
+fn parse_alter_user() {
+    match pg().verified_stmt("ALTER USER u WITH NOLOGIN PASSWORD NULL") {
+        Statement::AlterUser(AlterUser {
+            if_exists,
+            name,
+            operation,
+        }) => {
+            assert!(!if_exists);
+            assert_eq!(name, Ident::new("u"));
+            assert_eq!(
+                operation,
+                AlterUserOperation::WithOptions(vec![
+                    RoleOption::Login(false),
+                    RoleOption::Password(Password::NullPassword),
+                ])
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    pg().verified_stmt("ALTER USER u RENAME TO v");
+    pg().one_statement_parses_to("ALTER USER u SUPERUSER", "ALTER USER u WITH SUPERUSER");
+
+    assert_eq!(
+        pg().parse_sql_statements("ALTER USER u FOO").unwrap_err(),
+        ParserError::ParserError(
+            "Expected: RENAME, SET, RESET or a role option after ALTER USER, found: FOO"
+                .to_string()
+        )
+    );
+}
+
+#[test]
+fn parse_alter_user_set_and_reset() {
+    match pg().verified_stmt("ALTER USER u SET search_path TO public") {
+        Statement::AlterUser(AlterUser { operation, .. }) => {
+            assert_eq!(
+                operation,
+                AlterUserOperation::SetConfig {
+                    config_name: ObjectName::from(vec![Ident::new("search_path")]),
+                    config_value: SetConfigValue::Value(Expr::Identifier(Ident::new("public"))),
+                }
+            );
+        }
+        _ => unreachable!(),
+    }
+    pg().one_statement_parses_to(
+        "ALTER USER u SET work_mem = '64MB'",
+        "ALTER USER u SET work_mem TO '64MB'",
+    );
+    pg().verified_stmt("ALTER USER u SET search_path TO DEFAULT");
+    pg().verified_stmt("ALTER USER u SET search_path FROM CURRENT");
+
+    match pg().verified_stmt("ALTER USER u RESET ALL") {
+        Statement::AlterUser(AlterUser { operation, .. }) => {
+            assert_eq!(operation, AlterUserOperation::Reset(ResetConfig::ALL));
+        }
+        _ => unreachable!(),
+    }
+    match pg().verified_stmt("ALTER USER u RESET search_path") {
+        Statement::AlterUser(AlterUser { operation, .. }) => {
+            assert_eq!(
+                operation,
+                AlterUserOperation::Reset(ResetConfig::ConfigName(ObjectName::from(vec![
+                    Ident::new("search_path")
+                ])))
+            );
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_delete_using_with_joins() {
+    let sql = "DELETE FROM t USING other JOIN third ON other.tid = third.id WHERE t.id = other.id AND third.active RETURNING t.id";
+    match pg().verified_stmt(sql) {
+        Statement::Delete(Delete {
+            from: FromTable::WithFromKeyword(from),
+            using: Some(using),
+            selection,
+            returning,
+            ..
+        }) => {
+            assert_eq!(
+                from,
+                vec![TableWithJoins {
+                    relation: table_from_name(ObjectName::from(vec![Ident::new("t")])),
+                    joins: vec![],
+                }]
+            );
+            assert_eq!(using.len(), 1);
+            assert_eq!(
+                using[0].relation,
+                table_from_name(ObjectName::from(vec![Ident::new("other")]))
+            );
+            assert_eq!(using[0].joins.len(), 1);
+            assert_eq!(
+                using[0].joins[0].relation,
+                table_from_name(ObjectName::from(vec![Ident::new("third")]))
+            );
+            assert!(selection.is_some());
+            assert!(returning.is_some());
+        }
+        _ => unreachable!(),
+    }
+
+    pg().verified_stmt("DELETE FROM t USING a, b WHERE t.id = a.id AND a.id = b.id");
+}
+
+#[test]
+fn parse_row_value_in_list() {
+    let select = pg().verified_only_select("SELECT * FROM t WHERE (a, b) IN ((1, 2), (3, 4))");
+    assert_eq!(
+        select.selection.unwrap(),
+        Expr::InList {
+            expr: Box::new(Expr::Tuple(vec![
+                Expr::Identifier(Ident::new("a")),
+                Expr::Identifier(Ident::new("b")),
+            ])),
+            list: vec![
+                Expr::Tuple(vec![Expr::value(number("1")), Expr::value(number("2"))]),
+                Expr::Tuple(vec![Expr::value(number("3")), Expr::value(number("4"))]),
+            ],
+            negated: false,
+        }
+    );
+
+    pg().verified_stmt("SELECT * FROM t WHERE (a, b) NOT IN ((1, 'x'), (2, 'y'))");
+    pg().verified_stmt("SELECT * FROM t WHERE (a, (b, c)) IN ((1, (2, 3)))");
+}
+
+#[test]
+fn parse_values_derived_table_column_aliases() {
+    let select = pg().verified_only_select("SELECT * FROM (VALUES (1, 2), (3, 4)) AS t (a, b)");
+    match only(select.from).relation {
+        TableFactor::Derived {
+            subquery, alias, ..
+        } => {
+            assert!(matches!(*subquery.body, SetExpr::Values(_)));
+            assert_eq!(
+                alias,
+                Some(TableAlias {
+                    name: Ident::new("t"),
+                    columns: vec![
+                        TableAliasColumnDef::from_name("a"),
+                        TableAliasColumnDef::from_name("b"),
+                    ],
+                })
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    pg().one_statement_parses_to(
+        "SELECT * FROM (VALUES (1, 2)) AS t(a, b)",
+        "SELECT * FROM (VALUES (1, 2)) AS t (a, b)",
+    );
+}
+
+#[test]
+fn parse_cte_in_data_modification_statements_with_joins() {
+    // a leading `WITH` wraps the DML statement in a `Query` with a DML body
+    match pg().verified_stmt("WITH x AS (SELECT 1 AS id) DELETE FROM t USING x WHERE t.id = x.id") {
+        Statement::Query(query) => {
+            assert_eq!(
+                query.with.unwrap().to_string(),
+                "WITH x AS (SELECT 1 AS id)"
+            );
+            match *query.body {
+                SetExpr::Delete(Statement::Delete(Delete { using, .. })) => {
+                    assert_eq!(using.unwrap().len(), 1);
+                }
+                other => panic!("Expected: DELETE, got: {other:?}"),
+            }
+        }
+        other => panic!("Expected: Query, got: {other:?}"),
+    }
+
+    match pg().verified_stmt(
+        "WITH x AS (SELECT 1 AS id, 2 AS a) UPDATE t SET a = x.a FROM x WHERE t.id = x.id RETURNING t.id",
+    ) {
+        Statement::Query(query) => {
+            assert!(query.with.is_some());
+            match *query.body {
+                SetExpr::Update(Statement::Update {
+                    from, returning, ..
+                }) => {
+                    assert!(from.is_some());
+                    assert!(returning.is_some());
+                }
+                other => panic!("Expected: UPDATE, got: {other:?}"),
+            }
+        }
+        other => panic!("Expected: Query, got: {other:?}"),
+    }
+
+    match pg().verified_stmt(
+        "WITH RECURSIVE x AS (SELECT 1 AS id) INSERT INTO t (id) SELECT id FROM x ON CONFLICT DO NOTHING RETURNING id",
+    ) {
+        Statement::Query(query) => {
+            assert!(query.with.unwrap().recursive);
+            match *query.body {
+                SetExpr::Insert(Statement::Insert(Insert { returning, on, .. })) => {
+                    assert!(on.is_some());
+                    assert!(returning.is_some());
+                }
+                other => panic!("Expected: INSERT, got: {other:?}"),
+            }
+        }
+        other => panic!("Expected: Query, got: {other:?}"),
+    }
+}
+
+#[test]
+fn parse_named_argument_function_operators() {
+    let select = pg().verified_only_select("SELECT make_interval(days := 10, hours => 2)");
+    match expr_from_projection(only(&select.projection)) {
+        Expr::Function(Function {
+            args: FunctionArguments::List(FunctionArgumentList { args, .. }),
+            ..
+        }) => {
+            assert_eq!(
+                args,
+                &vec![
+                    FunctionArg::ExprNamed {
+                        name: Expr::Identifier(Ident::new("days")),
+                        arg: FunctionArgExpr::Expr(Expr::value(number("10"))),
+                        operator: FunctionArgOperator::Assignment,
+                    },
+                    FunctionArg::ExprNamed {
+                        name: Expr::Identifier(Ident::new("hours")),
+                        arg: FunctionArgExpr::Expr(Expr::value(number("2"))),
+                        operator: FunctionArgOperator::RightArrow,
+                    },
+                ]
+            );
+        }
+        other => panic!("Expected: function, got: {other:?}"),
+    }
+
+    pg().verified_stmt("SELECT * FROM generate_series(start => 1, stop := 3)");
+}
+
+#[test]
+fn parse_ordered_set_aggregate_with_filter() {
+    let select = pg().verified_only_select(
+        "SELECT PERCENTILE_CONT(0.5) WITHIN GROUP (ORDER BY x) FILTER (WHERE y > 0) FROM t",
+    );
+    match expr_from_projection(only(&select.projection)) {
+        Expr::Function(Function {
+            within_group,
+            filter,
+            ..
+        }) => {
+            assert_eq!(
+                within_group,
+                &vec![OrderByExpr {
+                    expr: Expr::Identifier(Ident::new("x")),
+                    options: OrderByOptions {
+                        asc: None,
+                        nulls_first: None,
+                    },
+                    with_fill: None,
+                }]
+            );
+            assert_eq!(
+                filter.as_deref(),
+                Some(&Expr::BinaryOp {
+                    left: Box::new(Expr::Identifier(Ident::new("y"))),
+                    op: BinaryOperator::Gt,
+                    right: Box::new(Expr::value(number("0"))),
+                })
+            );
+        }
+        other => panic!("Expected: function, got: {other:?}"),
+    }
+
+    pg().verified_stmt(
+        "SELECT PERCENTILE_CONT(0.5) WITHIN GROUP (ORDER BY x) FILTER (WHERE y > 0) OVER (PARTITION BY z) FROM t",
+    );
+}
+
+#[test]
+fn parse_create_aggregate() {
+    let sql = "CREATE AGGREGATE avg (FLOAT8) (SFUNC = float8_accum, STYPE = FLOAT8[], FINALFUNC = float8_avg, INITCOND = '{0,0,0}')";
+    match pg().verified_stmt(sql) {
+        Statement::CreateAggregate(CreateAggregate {
+            or_replace,
+            name,
+            args,
+            options,
+        }) => {
+            assert!(!or_replace);
+            assert_eq!(name, ObjectName::from(vec![Ident::new("avg")]));
+            assert_eq!(args, vec![OperateFunctionArg::unnamed(DataType::Float8)]);
+            assert_eq!(
+                options,
+                vec![
+                    CreateAggregateOption::KeyValue {
+                        key: Ident::new("SFUNC"),
+                        value: Expr::Identifier(Ident::new("float8_accum")),
+                    },
+                    CreateAggregateOption::DataType {
+                        key: Ident::new("STYPE"),
+                        data_type: DataType::Array(ArrayElemTypeDef::SquareBracket(
+                            Box::new(DataType::Float8),
+                            None
+                        )),
+                    },
+                    CreateAggregateOption::KeyValue {
+                        key: Ident::new("FINALFUNC"),
+                        value: Expr::Identifier(Ident::new("float8_avg")),
+                    },
+                    CreateAggregateOption::KeyValue {
+                        key: Ident::new("INITCOND"),
+                        value: Expr::Value(
+                            Value::SingleQuotedString("{0,0,0}".to_string()).with_empty_span()
+                        ),
+                    },
+                ]
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    pg().verified_stmt("CREATE OR REPLACE AGGREGATE s (IN x INT, TEXT) (SFUNC = f, STYPE = DOUBLE PRECISION, PARALLEL = SAFE, FINALFUNC_EXTRA)");
+    pg().verified_stmt("CREATE AGGREGATE cnt () (SFUNC = int8inc, STYPE = INT8, INITCOND = 0)");
+    // state types are recognized regardless of case
+    match pg().verified_stmt("CREATE AGGREGATE a (INT) (sfunc = f, mstype = INT[])") {
+        Statement::CreateAggregate(CreateAggregate { options, .. }) => {
+            assert!(matches!(
+                &options[1],
+                CreateAggregateOption::DataType { key, .. } if key.value == "mstype"
+            ));
+        }
+        _ => unreachable!(),
+    }
+
+    let sql = "CREATE AGGREGATE a (INT) (SFUNC = f, STYPE = INT)";
+    let not_pg = all_dialects_except(|d| d.is::<PostgreSqlDialect>() || d.is::<GenericDialect>());
+    for dialect in &not_pg.dialects {
+        assert!(
+            sqlparser::parser::Parser::parse_sql(&**dialect, sql).is_err(),
+            "{dialect:?}"
+        );
+    }
+}
+
+#[test]
+fn parse_table_statement() {
+    match pg().verified_stmt("TABLE t") {
+        Statement::Query(query) => assert_eq!(
+            *query.body,
+            SetExpr::Table(Box::new(Table {
+                table_name: Some("t".to_string()),
+                schema_name: None,
+            }))
+        ),
+        _ => unreachable!(),
+    }
+
+    match pg().verified_stmt("TABLE t ORDER BY x LIMIT 5") {
+        Statement::Query(query) => {
+            assert!(matches!(*query.body, SetExpr::Table(_)));
+            assert!(query.order_by.is_some());
+            assert!(query.limit_clause.is_some());
+        }
+        _ => unreachable!(),
+    }
+
+    pg().verified_stmt("TABLE s.t UNION TABLE u");
+    pg().verified_stmt("TABLE t UNION TABLE s.u ORDER BY 1");
+}
+
+#[test]
+fn parse_grant_role_membership() {
+    match pg().verified_stmt("GRANT r1, r2 TO u1 WITH ADMIN OPTION GRANTED BY CURRENT_USER") {
+        Statement::Grant {
+            privileges,
+            objects,
+            grantees,
+            with_grant_option,
+            with_admin_option,
+            granted_by,
+            ..
+        } => {
+            assert_eq!(
+                privileges,
+                Privileges::Roles(vec![
+                    ObjectName::from(vec![Ident::new("r1")]),
+                    ObjectName::from(vec![Ident::new("r2")]),
+                ])
+            );
+            assert_eq!(objects, None);
+            assert_eq!(grantees.len(), 1);
+            assert!(!with_grant_option);
+            assert!(with_admin_option);
+            assert_eq!(granted_by, Some(Ident::new("CURRENT_USER")));
+        }
+        _ => unreachable!(),
+    }
+
+    pg().verified_stmt("GRANT r1 TO u1");
+    pg().verified_stmt("REVOKE r1 FROM u1");
+    pg().verified_stmt("GRANT SELECT ON t TO u WITH GRANT OPTION GRANTED BY admin");
+
+    // a role name cannot be granted on an object
+    assert_eq!(
+        pg().parse_sql_statements("GRANT foo ON t TO u")
+            .unwrap_err()
+            .to_string(),
+        "sql parser error: Expected: a privilege keyword, found: foo"
+    );
+
+    // without `ON <objects>`, privilege keywords are role names
+    match pg().verified_stmt("GRANT usage TO bob") {
+        Statement::Grant {
+            privileges,
+            objects,
+            ..
+        } => {
+            assert_eq!(
+                privileges,
+                Privileges::Roles(vec![ObjectName::from(vec![Ident::new("usage")])])
+            );
+            assert_eq!(objects, None);
+        }
+        _ => unreachable!(),
+    }
+    match pg().verified_stmt("GRANT USAGE ON SCHEMA s TO bob") {
+        Statement::Grant { privileges, .. } => {
+            assert_eq!(privileges, Privileges::Actions(vec![Action::Usage]));
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_ilike_predicate() {
+    let select = pg().verified_only_select(
+        "SELECT * FROM t WHERE name NOT ILIKE 'a%' AND email ILIKE '%@x.com'",
+    );
+    assert_eq!(
+        select.selection.unwrap(),
+        Expr::BinaryOp {
+            left: Box::new(Expr::ILike {
+                negated: true,
+                any: false,
+                expr: Box::new(Expr::Identifier(Ident::new("name"))),
+                pattern: Box::new(Expr::Value(
+                    Value::SingleQuotedString("a%".to_string()).with_empty_span()
+                )),
+                escape_char: None,
+            }),
+            op: BinaryOperator::And,
+            right: Box::new(Expr::ILike {
+                negated: false,
+                any: false,
+                expr: Box::new(Expr::Identifier(Ident::new("email"))),
+                pattern: Box::new(Expr::Value(
+                    Value::SingleQuotedString("%@x.com".to_string()).with_empty_span()
+                )),
+                escape_char: None,
+            }),
+        }
+    );
+
+    pg().verified_stmt("SELECT name ILIKE 'a%' AS m FROM t");
+    pg().verified_stmt("SELECT * FROM t WHERE name NOT ILIKE 'a\\%%' ESCAPE '\\'");
+}
+
+#[test]
+fn parse_at_time_zone_interval() {
+    let select =
+        pg().verified_only_select("SELECT ts AT TIME ZONE INTERVAL '-08:00' HOUR TO MINUTE FROM t");
+    assert_eq!(
+        expr_from_projection(only(&select.projection)),
+        &Expr::AtTimeZone {
+            timestamp: Box::new(Expr::Identifier(Ident::new("ts"))),
+            time_zone: Box::new(Expr::Interval(Interval {
+                value: Box::new(Expr::Value(
+                    Value::SingleQuotedString("-08:00".to_string()).with_empty_span()
+                )),
+                leading_field: Some(DateTimeField::Hour),
+                leading_precision: None,
+                last_field: Some(DateTimeField::Minute),
+                fractional_seconds_precision: None,
+            })),
+        }
+    );
+
+    pg().verified_stmt("SELECT ts AT TIME ZONE INTERVAL '-08:00' FROM t");
+    pg().verified_stmt("SELECT ts AT TIME ZONE tz_col FROM t");
+}
+
+#[test]
+fn parse_create_view_security_invoker_option() {
+    match pg().verified_stmt("CREATE VIEW v WITH (security_invoker = true) AS SELECT 1") {
+        Statement::CreateView {
+            options, params, ..
+        } => {
+            // PostgreSQL expresses view security as a storage option, not a keyword
+            assert_eq!(params, None);
+            assert_eq!(
+                options,
+                CreateTableOptions::With(vec![SqlOption::KeyValue {
+                    key: "security_invoker".into(),
+                    value: Expr::Value(Value::Boolean(true).with_empty_span()),
+                }])
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    pg().verified_stmt(
+        "CREATE OR REPLACE VIEW v WITH (security_barrier = true, security_invoker = false) AS SELECT 1",
+    );
+}
+
+#[test]
+fn parse_window_frame_groups() {
+    let select = pg().verified_only_select(
+        "SELECT SUM(a) OVER (ORDER BY b GROUPS BETWEEN 1 PRECEDING AND 1 FOLLOWING) FROM t",
+    );
+    match expr_from_projection(only(&select.projection)) {
+        Expr::Function(Function {
+            over: Some(WindowType::WindowSpec(WindowSpec { window_frame, .. })),
+            ..
+        }) => assert_eq!(
+            window_frame,
+            &Some(WindowFrame {
+                units: WindowFrameUnits::Groups,
+                start_bound: WindowFrameBound::Preceding(Some(Box::new(Expr::value(number("1"))))),
+                end_bound: Some(WindowFrameBound::Following(Some(Box::new(Expr::value(
+                    number("1")
+                ))))),
+                exclusion: None,
+            })
+        ),
+        other => panic!("Expected: window function, got: {other:?}"),
+    }
+
+    pg().verified_stmt("SELECT SUM(a) OVER (ORDER BY b GROUPS UNBOUNDED PRECEDING) FROM t");
+    pg().verified_stmt(
+        "SELECT SUM(a) OVER (ORDER BY b GROUPS BETWEEN CURRENT ROW AND UNBOUNDED FOLLOWING EXCLUDE TIES) FROM t",
+    );
+}
@@ -19,10 +19,13 @@
 //! Test SQL syntax specific to Snowflake. The parser based on the
 //! generic dialect is also tested (on the inputs it can handle).
 
-use sqlparser::ast::helpers::key_value_options::{KeyValueOption, KeyValueOptionType};
+use sqlparser::ast::helpers::key_value_options::{
+    KeyValueOption, KeyValueOptionType, KeyValueOptions, KeyValueOptionsDelimiter,
+};
 use sqlparser::ast::helpers::stmt_data_loading::{StageLoadSelectItem, StageLoadSelectItemKind};
 use sqlparser::ast::*;
 use sqlparser::dialect::{Dialect, GenericDialect, SnowflakeDialect};
+use sqlparser::keywords::Keyword;
 use sqlparser::parser::{ParserError, ParserOptions};
 use sqlparser::tokenizer::*;
 use test_utils::*;
@@ -353,6 +356,68 @@ fn test_snowflake_create_table_column_comment() {
     }
 }
 
+#[test]
+fn test_snowflake_create_table_semi_structured_default() {
+    let sql = "CREATE TABLE my_table (a ARRAY DEFAULT [1, 2, 3], b OBJECT DEFAULT {'k': 1})";
+    match snowflake().verified_stmt(sql) {
+        Statement::CreateTable(CreateTable { columns, .. }) => {
+            assert_eq!(
+                vec![ColumnOptionDef {
+                    name: None,
+                    option: ColumnOption::Default(Expr::Array(Array {
+                        elem: vec![
+                            Expr::value(number("1")),
+                            Expr::value(number("2")),
+                            Expr::value(number("3")),
+                        ],
+                        named: false,
+                    })),
+                }],
+                columns[0].options
+            );
+            assert_eq!(
+                vec![ColumnOptionDef {
+                    name: None,
+                    option: ColumnOption::Default(Expr::Dictionary(vec![DictionaryField {
+                        key: Ident::with_quote('\'', "k"),
+                        value: Box::new(Expr::value(number("1"))),
+                    }])),
+                }],
+                columns[1].options
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    snowflake().verified_stmt(
+        "CREATE TABLE my_table (a ARRAY DEFAULT [] NOT NULL, b VARIANT DEFAULT {'k': [1, 2]})",
+    );
+}
+
+#[test]
+fn test_snowflake_create_table_column_default_sequence() {
+    let sql = "CREATE TABLE my_table (id INT DEFAULT my_seq.NEXTVAL, b INT)";
+    match snowflake().verified_stmt(sql) {
+        Statement::CreateTable(CreateTable { columns, .. }) => {
+            assert_eq!(
+                vec![ColumnOptionDef {
+                    name: None,
+                    option: ColumnOption::Default(Expr::CompoundIdentifier(vec![
+                        Ident::new("my_seq"),
+                        Ident::new("NEXTVAL"),
+                    ])),
+                }],
+                columns[0].options
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    snowflake().verified_stmt(
+        "CREATE TABLE my_table (id NUMBER DEFAULT db.sch.my_seq.NEXTVAL NOT NULL, b INT)",
+    );
+}
+
 #[test]
 fn test_snowflake_create_table_on_commit() {
     snowflake().verified_stmt(
@@ -492,6 +557,7 @@ fn test_snowflake_create_table_cluster_by() {
                             clauses: vec![],
                         }),
                         filter: None,
+                        from_first: None,
                         null_treatment: None,
                         over: None,
                         within_group: vec![],
@@ -1188,12 +1254,54 @@ fn parse_array() {
         },
         expr_from_projection(only(&select.projection))
     );
+
+    // structured array with an element type
+    let sql = "SELECT CAST(a AS ARRAY(INTEGER)) FROM customer";
+    let canonical = "SELECT CAST(a AS Array(INTEGER)) FROM customer";
+    let select = snowflake().verified_only_select_with_canonical(sql, canonical);
+    assert_eq!(
+        &Expr::Cast {
+            kind: CastKind::Cast,
+            expr: Box::new(Expr::Identifier(Ident::new("a"))),
+            data_type: DataType::Array(ArrayElemTypeDef::Parenthesis(Box::new(DataType::Integer(
+                None
+            )))),
+            format: None,
+        },
+        expr_from_projection(only(&select.projection))
+    );
+    snowflake().verified_only_select(canonical);
+    snowflake().one_statement_parses_to(
+        "SELECT a::ARRAY(ARRAY(VARCHAR)) FROM customer",
+        "SELECT a::Array(Array(VARCHAR)) FROM customer",
+    );
 }
 
 #[test]
 fn parse_lateral_flatten() {
     snowflake().verified_only_select(r#"SELECT * FROM TABLE(FLATTEN(input => parse_json('{"a":1, "b":[77,88]}'), outer => true)) AS f"#);
     snowflake().verified_only_select(r#"SELECT emp.employee_ID, emp.last_name, index, value AS project_name FROM employees AS emp, LATERAL FLATTEN(INPUT => emp.project_names) AS proj_names"#);
+
+    // the path argument is preserved verbatim, including bracketed indexes
+    let select = snowflake().verified_only_select(
+        "SELECT f.value FROM t, LATERAL FLATTEN(input => t.v, path => 'items[0].name') AS f",
+    );
+    match &select.from[1].relation {
+        TableFactor::Function { lateral, args, .. } => {
+            assert!(lateral);
+            assert_eq!(
+                FunctionArg::Named {
+                    name: Ident::new("path"),
+                    arg: FunctionArgExpr::Expr(Expr::value(Value::SingleQuotedString(
+                        "items[0].name".to_string()
+                    ))),
+                    operator: FunctionArgOperator::RightArrow,
+                },
+                args[1]
+            );
+        }
+        _ => unreachable!(),
+    }
 }
 
 // https://docs.snowflake.com/en/user-guide/querying-semistructured
@@ -1415,6 +1523,45 @@ fn parse_semi_structured_data_traversal() {
     );
 }
 
+#[test]
+fn parse_semi_structured_is_null() {
+    let json_access = |key: &str| Expr::JsonAccess {
+        value: Box::new(Expr::Identifier(Ident::new("v"))),
+        path: JsonPath {
+            path: vec![JsonPathElem::Dot {
+                key: key.to_string(),
+                quoted: false,
+            }],
+        },
+    };
+
+    let select = snowflake().verified_only_select("SELECT * FROM t WHERE v:field IS NULL");
+    assert_eq!(
+        select.selection,
+        Some(Expr::IsNull(Box::new(json_access("field"))))
+    );
+
+    let select = snowflake().verified_only_select("SELECT * FROM t WHERE v:field IS NOT NULL");
+    assert_eq!(
+        select.selection,
+        Some(Expr::IsNotNull(Box::new(json_access("field"))))
+    );
+
+    let select =
+        snowflake().verified_only_select("SELECT * FROM t WHERE v:a IS NULL AND v:b IS NOT NULL");
+    assert_eq!(
+        select.selection,
+        Some(Expr::BinaryOp {
+            left: Box::new(Expr::IsNull(Box::new(json_access("a")))),
+            op: BinaryOperator::And,
+            right: Box::new(Expr::IsNotNull(Box::new(json_access("b")))),
+        })
+    );
+
+    snowflake().verified_stmt("SELECT v:a.b[0] IS NULL FROM t");
+    snowflake().verified_stmt("SELECT v:a::STRING IS NOT NULL FROM t");
+}
+
 #[test]
 fn parse_delimited_identifiers() {
     // check that quoted identifiers in any position remain quoted after serialization
@@ -1462,6 +1609,7 @@ fn parse_delimited_identifiers() {
                 clauses: vec![],
             }),
             filter: None,
+            from_first: None,
             null_treatment: None,
             over: None,
             within_group: vec![],
@@ -1676,6 +1824,7 @@ fn test_alter_table_clustering() {
                             clauses: vec![],
                         }),
                         filter: None,
+                        from_first: None,
                         null_treatment: None,
                         over: None,
                         within_group: vec![]
@@ -1691,6 +1840,56 @@ fn test_alter_table_clustering() {
     snowflake_and_generic().verified_stmt("ALTER TABLE tbl RESUME RECLUSTER");
 }
 
+#[test]
+fn test_alter_table_set_unset_tags() {
+    let sql = "ALTER TABLE tab SET TAG a='x', db.sch.b='y'";
+    match alter_table_op(snowflake_and_generic().verified_stmt(sql)) {
+        AlterTableOperation::SetTags { tags } => {
+            assert_eq!(
+                tags,
+                vec![
+                    Tag::new(ObjectName::from(vec![Ident::new("a")]), "x".to_string()),
+                    Tag::new(
+                        ObjectName::from(vec![
+                            Ident::new("db"),
+                            Ident::new("sch"),
+                            Ident::new("b")
+                        ]),
+                        "y".to_string()
+                    ),
+                ]
+            );
+        }
+        _ => unreachable!(),
+    }
+    snowflake_and_generic().one_statement_parses_to(
+        "ALTER TABLE tbl SET TAG a = 'x', b = 'y'",
+        "ALTER TABLE tbl SET TAG a='x', b='y'",
+    );
+
+    let sql = "ALTER TABLE tab UNSET TAG a, db.sch.b";
+    match alter_table_op(snowflake_and_generic().verified_stmt(sql)) {
+        AlterTableOperation::UnsetTags { keys } => {
+            assert_eq!(
+                keys,
+                vec![
+                    ObjectName::from(vec![Ident::new("a")]),
+                    ObjectName::from(vec![Ident::new("db"), Ident::new("sch"), Ident::new("b")]),
+                ]
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    assert_eq!(
+        snowflake()
+            .parse_sql_statements("ALTER TABLE tbl SET TAG a")
+            .unwrap_err()
+            .to_string(),
+        "sql parser error: Expected: =, found: EOF"
+    );
+}
+
 #[test]
 fn test_alter_iceberg_table() {
     snowflake_and_generic().verified_stmt("ALTER ICEBERG TABLE tbl DROP CLUSTERING KEY");
@@ -2547,6 +2746,85 @@ fn test_copy_into_copy_options() {
     assert_eq!(snowflake().verified_stmt(sql).to_string(), sql);
 }
 
+#[test]
+fn test_copy_into_copy_options_order() {
+    let option_names = |sql: &str| match snowflake().parse_sql_statements(sql).unwrap().pop() {
+        Some(Statement::CopyIntoSnowflake { copy_options, .. }) => copy_options
+            .options
+            .into_iter()
+            .map(|o| o.option_name)
+            .collect::<Vec<_>>(),
+        _ => unreachable!(),
+    };
+
+    // Options keep their original order, both bare and within `COPY_OPTIONS`
+    for (sql, expected) in [
+        (
+            "COPY INTO t FROM @s COPY_OPTIONS=(FORCE=TRUE ON_ERROR=CONTINUE)",
+            vec!["FORCE", "ON_ERROR"],
+        ),
+        (
+            "COPY INTO t FROM @s COPY_OPTIONS=(ON_ERROR=CONTINUE FORCE=TRUE)",
+            vec!["ON_ERROR", "FORCE"],
+        ),
+    ] {
+        assert_eq!(snowflake().verified_stmt(sql).to_string(), sql);
+        assert_eq!(option_names(sql), expected);
+    }
+    snowflake().one_statement_parses_to(
+        "COPY INTO t FROM @s ON_ERROR = 'SKIP_FILE' FORCE = TRUE",
+        "COPY INTO t FROM @s COPY_OPTIONS=(ON_ERROR='SKIP_FILE' FORCE=TRUE)",
+    );
+    snowflake().one_statement_parses_to(
+        "COPY INTO t FROM @s FORCE = TRUE, ON_ERROR = CONTINUE",
+        "COPY INTO t FROM @s COPY_OPTIONS=(FORCE=TRUE ON_ERROR=CONTINUE)",
+    );
+
+    // Bare options are not dropped when followed by `COPY_OPTIONS`
+    assert_eq!(
+        option_names(
+            "COPY INTO t FROM @s PURGE = TRUE COPY_OPTIONS=(FORCE=TRUE ON_ERROR=CONTINUE)"
+        ),
+        vec!["PURGE", "FORCE", "ON_ERROR"]
+    );
+
+    assert_eq!(
+        snowflake()
+            .parse_sql_statements("COPY INTO t FROM @s FORCE TRUE")
+            .unwrap_err()
+            .to_string(),
+        "sql parser error: Expected: = after copy option FORCE, found: TRUE"
+    );
+    assert_eq!(
+        snowflake()
+            .parse_sql_statements("COPY INTO t FROM @s ON_ERROR = CONTINUE 'x'")
+            .unwrap_err()
+            .to_string(),
+        "sql parser error: Expected: another copy option, ; or EOF, found: 'x'"
+    );
+
+    // Unknown option names are rejected rather than collected into `COPY_OPTIONS`
+    assert_eq!(
+        snowflake()
+            .parse_sql_statements("COPY INTO t FROM @s BOGUS_OPT = 1")
+            .unwrap_err()
+            .to_string(),
+        "sql parser error: Expected: a copy option, found: BOGUS_OPT"
+    );
+    // `COPY INTO <location>` has its own set of copy options
+    snowflake().one_statement_parses_to(
+        "COPY INTO @s FROM t OVERWRITE = TRUE SINGLE = TRUE",
+        "COPY INTO @s FROM t OVERWRITE=TRUE SINGLE=TRUE",
+    );
+    assert_eq!(
+        snowflake()
+            .parse_sql_statements("COPY INTO @s FROM t PURGE = TRUE")
+            .unwrap_err()
+            .to_string(),
+        "sql parser error: Expected: a copy option, found: PURGE"
+    );
+}
+
 #[test]
 fn test_snowflake_stage_object_names_into_location() {
     let mut allowed_object_names = [
@@ -2646,6 +2924,40 @@ fn test_snowflake_copy_into() {
         }
         _ => unreachable!(),
     }
+
+    // Test for query-string-like and URL-encoded stage paths
+    let sql = "COPY INTO a.b FROM @namespace.stage_name/year=2025/data-01?ver=2&x=%2F";
+    match snowflake().verified_stmt(sql) {
+        Statement::CopyIntoSnowflake { from_obj, .. } => {
+            assert_eq!(
+                from_obj,
+                Some(ObjectName::from(vec![
+                    Ident::new("@namespace"),
+                    Ident::new("stage_name/year=2025/data-01?ver=2&x=%2F")
+                ]))
+            )
+        }
+        _ => unreachable!(),
+    }
+
+    // A semicolon terminates the stage name and the statement
+    let stmts = snowflake()
+        .parse_sql_statements("COPY INTO a.b FROM @~/path?x=1; SELECT 1")
+        .unwrap();
+    assert_eq!(stmts.len(), 2);
+    match &stmts[0] {
+        Statement::CopyIntoSnowflake { from_obj, .. } => {
+            assert_eq!(
+                from_obj,
+                &Some(ObjectName::from(vec![Ident::new("@~/path?x=1")]))
+            )
+        }
+        _ => unreachable!(),
+    }
+    let stmts = snowflake()
+        .parse_sql_statements("COPY INTO @namespace.%t/a=1 FROM a.b; SELECT 1")
+        .unwrap();
+    assert_eq!(stmts.len(), 2);
 }
 
 #[test]
@@ -2917,6 +3229,68 @@ fn first_value_ignore_nulls() {
         "OVER (PARTITION BY column1 ORDER BY column2) ",
         "FROM some_table"
     ));
+
+    // `IGNORE NULLS` / `RESPECT NULLS` after the closing parenthesis
+    for (sql, null_treatment) in [
+        (
+            "SELECT LAG(x) IGNORE NULLS OVER (ORDER BY y) FROM t",
+            NullTreatment::IgnoreNulls,
+        ),
+        (
+            "SELECT LAG(x) RESPECT NULLS OVER (ORDER BY y) FROM t",
+            NullTreatment::RespectNulls,
+        ),
+    ] {
+        let select = snowflake().verified_only_select(sql);
+        match expr_from_projection(only(&select.projection)) {
+            Expr::Function(f) => assert_eq!(f.null_treatment, Some(null_treatment)),
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[test]
+fn nth_value_from_first_or_last() {
+    let sql = "SELECT NTH_VALUE(x, 2) FROM FIRST IGNORE NULLS OVER (ORDER BY y) FROM t";
+    let select = snowflake().verified_only_select(sql);
+    match expr_from_projection(only(&select.projection)) {
+        Expr::Function(Function {
+            from_first,
+            null_treatment,
+            over,
+            ..
+        }) => {
+            assert_eq!(&Some(true), from_first);
+            assert_eq!(&Some(NullTreatment::IgnoreNulls), null_treatment);
+            assert!(over.is_some());
+        }
+        _ => unreachable!(),
+    }
+
+    let sql = "SELECT NTH_VALUE(x, 2) FROM LAST OVER (ORDER BY y) FROM t";
+    let select = snowflake().verified_only_select(sql);
+    match expr_from_projection(only(&select.projection)) {
+        Expr::Function(Function {
+            from_first,
+            null_treatment,
+            ..
+        }) => {
+            assert_eq!(&Some(false), from_first);
+            assert_eq!(&None, null_treatment);
+        }
+        _ => unreachable!(),
+    }
+
+    snowflake().verified_stmt(
+        "SELECT NTH_VALUE(x, 2) FROM LAST RESPECT NULLS OVER (PARTITION BY z ORDER BY y ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING) FROM t",
+    );
+
+    // Without a null treatment or window, `FROM FIRST` is the query's FROM clause
+    let select = snowflake().verified_only_select("SELECT COUNT(x) FROM first");
+    match expr_from_projection(only(&select.projection)) {
+        Expr::Function(Function { from_first, .. }) => assert_eq!(&None, from_first),
+        _ => unreachable!(),
+    }
 }
 
 #[test]
@@ -3354,6 +3728,41 @@ fn test_parse_show_tables() {
         .verified_stmt("SHOW EXTERNAL TABLES IN SCHEMA STARTS WITH 'abc' LIMIT 20 FROM 'xyz'");
 }
 
+#[test]
+fn test_parse_show_tables_like_with_escape() {
+    // The backslash is preserved when the tokenizer does not unescape literals
+    match snowflake_without_unescape().verified_stmt(r"SHOW TABLES LIKE 'a\_b'") {
+        Statement::ShowTables { show_options, .. } => {
+            assert_eq!(
+                show_options.filter_position,
+                Some(ShowStatementFilterPosition::Infix(
+                    ShowStatementFilter::Like(r"a\_b".to_string())
+                ))
+            );
+        }
+        _ => unreachable!(),
+    }
+    snowflake_without_unescape().verified_stmt(r"SHOW TABLES LIKE 'a\\_b%' IN SCHEMA xyz");
+    snowflake_without_unescape().verified_stmt(r"SHOW COLUMNS LIKE 'x\%y' IN TABLE abc");
+    snowflake_without_unescape().verified_stmt(r"SHOW TABLES ILIKE 'a\_b'");
+
+    // With unescaping, an escaped backslash is kept as a single backslash
+    let stmts = snowflake()
+        .parse_sql_statements(r"SHOW TABLES LIKE 'a\\_b'")
+        .unwrap();
+    match only(stmts) {
+        Statement::ShowTables { show_options, .. } => {
+            assert_eq!(
+                show_options.filter_position,
+                Some(ShowStatementFilterPosition::Infix(
+                    ShowStatementFilter::Like(r"a\_b".to_string())
+                ))
+            );
+        }
+        _ => unreachable!(),
+    }
+}
+
 #[test]
 fn test_show_views() {
     snowflake().verified_stmt("SHOW VIEWS");
@@ -3427,6 +3836,28 @@ fn test_parse_double_dot_notation_wrong_position() {}
 fn parse_insert_overwrite() {
     let insert_overwrite_into = r#"INSERT OVERWRITE INTO schema.table SELECT a FROM b"#;
     snowflake().verified_stmt(insert_overwrite_into);
+
+    let sql = "INSERT OVERWRITE INTO t (a, b) VALUES (1, 'x'), (2, 'y'), (3, 'z')";
+    match snowflake().verified_stmt(sql) {
+        Statement::Insert(Insert {
+            overwrite,
+            into,
+            columns,
+            source: Some(source),
+            ..
+        }) => {
+            assert!(overwrite);
+            assert!(into);
+            assert_eq!(vec![Ident::new("a"), Ident::new("b")], columns);
+            match *source.body {
+                SetExpr::Values(Values { rows, .. }) => assert_eq!(3, rows.len()),
+                _ => unreachable!(),
+            }
+        }
+        _ => unreachable!(),
+    }
+
+    snowflake().verified_stmt("INSERT OVERWRITE INTO t VALUES (1), (2), (3)");
 }
 
 #[test]
@@ -3546,6 +3977,41 @@ fn test_sql_keywords_as_select_item_aliases() {
     snowflake().verified_stmt("SELECT 1 LIMIT $$$$");
 }
 
+#[test]
+fn test_reserved_keywords_for_column_alias() {
+    let dialect = SnowflakeDialect {};
+    for kw in [
+        Keyword::LIMIT,
+        Keyword::FETCH,
+        Keyword::EXCEPT,
+        Keyword::SORT,
+    ] {
+        assert!(!dialect.is_reserved_for_column_alias(&kw), "{kw:?}");
+    }
+    for kw in [Keyword::FROM, Keyword::WHERE, Keyword::UNION, Keyword::WITH] {
+        assert!(dialect.is_reserved_for_column_alias(&kw), "{kw:?}");
+    }
+
+    let reserved = dialect.get_reserved_keywords_for_column_alias();
+    assert!(reserved.contains(&Keyword::FROM));
+    assert!(!reserved.contains(&Keyword::LIMIT));
+
+    // the generic dialect reserves `LIMIT`
+    assert!(GenericDialect {}.is_reserved_for_column_alias(&Keyword::LIMIT));
+
+    assert!(!dialect.is_reserved_for_table_alias(&Keyword::LIMIT));
+    assert!(dialect.is_reserved_for_table_alias(&Keyword::FROM));
+    assert!(dialect
+        .get_reserved_keywords_for_table_alias()
+        .contains(&Keyword::QUALIFY));
+
+    // Snowflake accepts `INTERVAL` as an identifier
+    assert!(dialect.reserved_keywords().contains(&Keyword::INTERVAL));
+    assert!(!dialect.is_reserved_for_identifier(Keyword::INTERVAL));
+    assert!(dialect.is_reserved_for_identifier(Keyword::EXISTS));
+    assert!(GenericDialect {}.is_reserved_for_identifier(Keyword::INTERVAL));
+}
+
 #[test]
 fn test_sql_keywords_as_table_aliases() {
     // Some keywords that should be parsed as an alias implicitly
@@ -4504,3 +4970,596 @@ fn test_snowflake_identifier_function() {
     snowflake().verified_stmt("GRANT ROLE IDENTIFIER('AAA') TO USER IDENTIFIER('AAA')");
     snowflake().verified_stmt("REVOKE ROLE IDENTIFIER('AAA') FROM USER IDENTIFIER('AAA')");
 }
+
+#[test]
+fn test_snowflake_merge_insert_with_default() {
+    let sql = concat!(
+        "MERGE INTO t USING s ON t.id = s.id ",
+        "WHEN MATCHED THEN UPDATE SET t.a = s.a ",
+        "WHEN NOT MATCHED THEN INSERT (a, b) VALUES (s.a, DEFAULT)"
+    );
+    match snowflake().verified_stmt(sql) {
+        Statement::Merge { clauses, .. } => {
+            assert_eq!(clauses.len(), 2);
+            assert_eq!(clauses[1].clause_kind, MergeClauseKind::NotMatched);
+            assert_eq!(
+                clauses[1].action,
+                MergeAction::Insert(MergeInsertExpr {
+                    columns: vec![Ident::new("a"), Ident::new("b")],
+                    kind: MergeInsertKind::Values(Values {
+                        explicit_row: false,
+                        rows: vec![vec![
+                            Expr::CompoundIdentifier(vec![Ident::new("s"), Ident::new("a")]),
+                            Expr::Identifier(Ident::new("DEFAULT")),
+                        ]],
+                    }),
+                })
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    snowflake().verified_stmt(
+        "MERGE INTO t USING s ON t.id = s.id WHEN NOT MATCHED THEN INSERT VALUES (s.a, DEFAULT)",
+    );
+    snowflake().verified_stmt(
+        "MERGE INTO t USING s ON t.id = s.id WHEN NOT MATCHED AND s.b > 0 THEN INSERT (a, b, c) VALUES (DEFAULT, s.b, s.c + 1)",
+    );
+}
+
+#[test]
+fn test_snowflake_named_window_with_frame() {
+    let sql = "SELECT SUM(x) OVER w FROM t WINDOW w AS (ORDER BY y ROWS UNBOUNDED PRECEDING)";
+    let select = snowflake().verified_only_select(sql);
+    match expr_from_projection(only(&select.projection)) {
+        Expr::Function(Function { over, .. }) => {
+            assert_eq!(over, &Some(WindowType::NamedWindow(Ident::new("w"))))
+        }
+        _ => unreachable!(),
+    }
+    assert_eq!(
+        select.named_window,
+        vec![NamedWindowDefinition(
+            Ident::new("w"),
+            NamedWindowExpr::WindowSpec(WindowSpec {
+                window_name: None,
+                partition_by: vec![],
+                order_by: vec![OrderByExpr {
+                    expr: Expr::Identifier(Ident::new("y")),
+                    options: OrderByOptions {
+                        asc: None,
+                        nulls_first: None,
+                    },
+                    with_fill: None,
+                }],
+                window_frame: Some(WindowFrame {
+                    units: WindowFrameUnits::Rows,
+                    start_bound: WindowFrameBound::Preceding(None),
+                    end_bound: None,
+                    exclusion: None,
+                }),
+            })
+        )]
+    );
+
+    snowflake().verified_stmt(concat!(
+        "SELECT SUM(x) OVER w, AVG(x) OVER (w ROWS BETWEEN 1 PRECEDING AND CURRENT ROW) ",
+        "FROM t WHERE x > 0 ",
+        "WINDOW w AS (PARTITION BY z ORDER BY y RANGE BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW)"
+    ));
+
+    // `WINDOW` is still accepted as a table alias
+    snowflake().one_statement_parses_to(
+        "SELECT window.a FROM t window",
+        "SELECT window.a FROM t AS window",
+    );
+}
+
+#[test]
+fn test_snowflake_variadic_conditional_functions() {
+    let select = snowflake().verified_only_select(
+        "SELECT GREATEST(a, b, c, 1, NULL), LEAST(a, b), BOOLOR_AGG(flag), GREATEST_IGNORE_NULLS(a, b, c) FROM t",
+    );
+    assert_eq!(
+        &call(
+            "GREATEST",
+            [
+                Expr::Identifier(Ident::new("a")),
+                Expr::Identifier(Ident::new("b")),
+                Expr::Identifier(Ident::new("c")),
+                Expr::value(number("1")),
+                Expr::Value(Value::Null.with_empty_span()),
+            ]
+        ),
+        expr_from_projection(&select.projection[0])
+    );
+    assert_eq!(
+        &call(
+            "LEAST",
+            [
+                Expr::Identifier(Ident::new("a")),
+                Expr::Identifier(Ident::new("b")),
+            ]
+        ),
+        expr_from_projection(&select.projection[1])
+    );
+    assert_eq!(
+        &call("BOOLOR_AGG", [Expr::Identifier(Ident::new("flag"))]),
+        expr_from_projection(&select.projection[2])
+    );
+    assert_eq!(
+        &call(
+            "GREATEST_IGNORE_NULLS",
+            [
+                Expr::Identifier(Ident::new("a")),
+                Expr::Identifier(Ident::new("b")),
+                Expr::Identifier(Ident::new("c")),
+            ]
+        ),
+        expr_from_projection(&select.projection[3])
+    );
+
+    snowflake().verified_stmt("SELECT LEAST(1)");
+    snowflake().verified_stmt("SELECT BOOLOR_AGG(a > 0) OVER (PARTITION BY b) FROM t");
+}
+
+#[test]
+fn test_snowflake_date_part_function_args() {
+    let select = snowflake().verified_only_select(
+        "SELECT DATEADD(day, 1, d), DATEDIFF('day', a, b), TIMESTAMPADD(HOUR, 1, ts)",
+    );
+    assert_eq!(
+        &call(
+            "DATEADD",
+            [
+                Expr::Identifier(Ident::new("day")),
+                Expr::value(number("1")),
+                Expr::Identifier(Ident::new("d")),
+            ]
+        ),
+        expr_from_projection(&select.projection[0])
+    );
+    assert_eq!(
+        &call(
+            "DATEDIFF",
+            [
+                Expr::value(Value::SingleQuotedString("day".to_string())),
+                Expr::Identifier(Ident::new("a")),
+                Expr::Identifier(Ident::new("b")),
+            ]
+        ),
+        expr_from_projection(&select.projection[1])
+    );
+    assert_eq!(
+        &call(
+            "TIMESTAMPADD",
+            [
+                Expr::Identifier(Ident::new("HOUR")),
+                Expr::value(number("1")),
+                Expr::Identifier(Ident::new("ts")),
+            ]
+        ),
+        expr_from_projection(&select.projection[2])
+    );
+
+    // date parts that are also keywords are accepted unquoted
+    snowflake().verified_stmt(
+        "SELECT DATEADD(year, -1, d), DATEADD(quarter, 1, d), DATEDIFF(week, a, b), DATEDIFF(second, a, b)",
+    );
+    snowflake().verified_stmt("SELECT DATEADD('month', 1, d), TIMESTAMPDIFF('minute', a, b)");
+}
+
+#[test]
+fn test_snowflake_values_table_with_column_aliases() {
+    let select = snowflake().verified_only_select_with_canonical(
+        "SELECT * FROM (VALUES (1, 'a'), (2, 'b')) AS v(id, name)",
+        "SELECT * FROM (VALUES (1, 'a'), (2, 'b')) AS v (id, name)",
+    );
+    match &select.from[0].relation {
+        TableFactor::Derived {
+            subquery,
+            alias: Some(alias),
+            ..
+        } => {
+            assert_eq!(
+                &TableAlias {
+                    name: Ident::new("v"),
+                    columns: vec![
+                        TableAliasColumnDef::from_name("id"),
+                        TableAliasColumnDef::from_name("name"),
+                    ],
+                },
+                alias
+            );
+            match subquery.body.as_ref() {
+                SetExpr::Values(Values { rows, .. }) => assert_eq!(2, rows.len()),
+                _ => unreachable!(),
+            }
+        }
+        _ => unreachable!(),
+    }
+
+    // the parentheses around `VALUES` are optional
+    snowflake().one_statement_parses_to(
+        "SELECT v.id FROM VALUES (1, 'a'), (2, 'b') AS v (id, name)",
+        "SELECT v.id FROM (VALUES (1, 'a'), (2, 'b')) AS v (id, name)",
+    );
+}
+
+#[test]
+fn test_snowflake_call_with_named_arguments() {
+    match snowflake().verified_stmt("CALL proc(a => 1, b => 'x')") {
+        Statement::Call(Function {
+            name,
+            args: FunctionArguments::List(FunctionArgumentList { args, .. }),
+            ..
+        }) => {
+            assert_eq!("proc", name.to_string());
+            assert_eq!(
+                vec![
+                    FunctionArg::Named {
+                        name: Ident::new("a"),
+                        arg: FunctionArgExpr::Expr(Expr::value(number("1"))),
+                        operator: FunctionArgOperator::RightArrow,
+                    },
+                    FunctionArg::Named {
+                        name: Ident::new("b"),
+                        arg: FunctionArgExpr::Expr(Expr::value(Value::SingleQuotedString(
+                            "x".to_string()
+                        ))),
+                        operator: FunctionArgOperator::RightArrow,
+                    },
+                ],
+                args
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    snowflake().verified_stmt("CALL db.sch.proc(1, 'x')");
+    snowflake().verified_stmt("CALL proc(1, b => 2)");
+    snowflake().verified_stmt("CALL proc()");
+}
+
+#[test]
+fn parse_semi_structured_object_literal_access() {
+    let select = snowflake().verified_only_select("SELECT {'a': 1}:a");
+    assert_eq!(
+        SelectItem::UnnamedExpr(Expr::JsonAccess {
+            value: Box::new(Expr::Dictionary(vec![DictionaryField {
+                key: Ident::with_quote('\'', "a"),
+                value: Box::new(Expr::value(number("1"))),
+            }])),
+            path: JsonPath {
+                path: vec![JsonPathElem::Dot {
+                    key: "a".to_owned(),
+                    quoted: false
+                }]
+            },
+        }),
+        select.projection[0]
+    );
+
+    snowflake().verified_stmt("SELECT {'a': {'b': [1, 2]}}:a.b[0] FROM t");
+}
+
+#[test]
+fn parse_connect_by_nocycle() {
+    let select = snowflake().verified_only_select(
+        "SELECT id, parent_id FROM t START WITH parent_id IS NULL CONNECT BY NOCYCLE PRIOR id = parent_id",
+    );
+    assert_eq!(
+        Some(ConnectBy {
+            condition: Expr::IsNull(Box::new(Expr::Identifier(Ident::new("parent_id")))),
+            relationships: vec![Expr::BinaryOp {
+                left: Box::new(Expr::Prior(Box::new(Expr::Identifier(Ident::new("id"))))),
+                op: BinaryOperator::Eq,
+                right: Box::new(Expr::Identifier(Ident::new("parent_id"))),
+            }],
+            nocycle: true,
+        }),
+        select.connect_by
+    );
+
+    snowflake().one_statement_parses_to(
+        "SELECT id FROM t CONNECT BY NOCYCLE PRIOR id = parent_id START WITH id = 1",
+        "SELECT id FROM t START WITH id = 1 CONNECT BY NOCYCLE PRIOR id = parent_id",
+    );
+}
+
+#[test]
+fn parse_recursive_cte() {
+    let sql = concat!(
+        "WITH RECURSIVE cte (id, parent_id, lvl) AS (",
+        "SELECT id, parent_id, 1 FROM t WHERE parent_id IS NULL ",
+        "UNION ALL ",
+        "SELECT t.id, t.parent_id, cte.lvl + 1 FROM t JOIN cte ON t.parent_id = cte.id",
+        ") SELECT * FROM cte"
+    );
+    let query = snowflake().verified_query(sql);
+    let with = query.with.unwrap();
+    assert!(with.recursive);
+    assert_eq!(1, with.cte_tables.len());
+    let cte = &with.cte_tables[0];
+    assert_eq!("cte (id, parent_id, lvl)", cte.alias.to_string());
+    match cte.query.body.as_ref() {
+        SetExpr::SetOperation {
+            op: SetOperator::Union,
+            set_quantifier: SetQuantifier::All,
+            left,
+            right,
+        } => {
+            assert_eq!(
+                "SELECT id, parent_id, 1 FROM t WHERE parent_id IS NULL",
+                left.to_string()
+            );
+            assert_eq!(
+                "SELECT t.id, t.parent_id, cte.lvl + 1 FROM t JOIN cte ON t.parent_id = cte.id",
+                right.to_string()
+            );
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn test_select_from_stage_with_positional_order_by() {
+    let select = snowflake().verified_only_select("SELECT $1, $2 FROM @stage ORDER BY $1");
+    assert_eq!(
+        vec![
+            SelectItem::UnnamedExpr(Expr::Value(
+                (Value::Placeholder("$1".to_string())).with_empty_span()
+            )),
+            SelectItem::UnnamedExpr(Expr::Value(
+                (Value::Placeholder("$2".to_string())).with_empty_span()
+            )),
+        ],
+        select.projection
+    );
+    assert_eq!(
+        table_from_name(ObjectName::from(vec![Ident::new("@stage")])),
+        select.from[0].relation
+    );
+
+    let query = snowflake().verified_query("SELECT $1, $2 FROM @stage ORDER BY $1");
+    match query.order_by.unwrap().kind {
+        OrderByKind::Expressions(exprs) => assert_eq!(
+            Expr::Value((Value::Placeholder("$1".to_string())).with_empty_span()),
+            exprs[0].expr
+        ),
+        _ => unreachable!(),
+    }
+
+    snowflake().verified_stmt("SELECT $1 FROM @~ ORDER BY $1");
+    snowflake().verified_stmt("SELECT $1, $2 FROM @%my_table WHERE $2 > 0 ORDER BY $2 DESC, $1");
+    snowflake().one_statement_parses_to(
+        "SELECT $1:a, $2 FROM @db.sch.stage/path (FILE_FORMAT => 'f', PATTERN => '.*[.]csv') AS t ORDER BY $1 DESC",
+        "SELECT $1:a, $2 FROM @db.sch.stage/path(FILE_FORMAT => 'f', PATTERN => '.*[.]csv') AS t ORDER BY $1 DESC",
+    );
+}
+
+#[test]
+fn parse_semi_structured_access_on_conversion_functions() {
+    let select =
+        snowflake().verified_only_select("SELECT TO_ARRAY(x)[0], TO_VARIANT(x):field FROM t");
+    assert_eq!(
+        &Expr::JsonAccess {
+            value: Box::new(call("TO_ARRAY", [Expr::Identifier(Ident::new("x"))])),
+            path: JsonPath {
+                path: vec![JsonPathElem::Bracket {
+                    key: Expr::value(number("0"))
+                }]
+            },
+        },
+        expr_from_projection(&select.projection[0])
+    );
+    assert_eq!(
+        &Expr::JsonAccess {
+            value: Box::new(call("TO_VARIANT", [Expr::Identifier(Ident::new("x"))])),
+            path: JsonPath {
+                path: vec![JsonPathElem::Dot {
+                    key: "field".to_owned(),
+                    quoted: false
+                }]
+            },
+        },
+        expr_from_projection(&select.projection[1])
+    );
+
+    snowflake().verified_stmt("SELECT TO_VARIANT(x):a.b[1], TO_ARRAY(x)[0]:c FROM t");
+}
+
+#[test]
+fn test_snowflake_case_in_window_order_by() {
+    let select = snowflake().verified_only_select(
+        "SELECT ROW_NUMBER() OVER (ORDER BY CASE WHEN a IS NULL THEN 1 ELSE 0 END, b) FROM t",
+    );
+    match expr_from_projection(&select.projection[0]) {
+        Expr::Function(Function {
+            over: Some(WindowType::WindowSpec(WindowSpec { order_by, .. })),
+            ..
+        }) => {
+            assert_eq!(2, order_by.len());
+            assert!(matches!(order_by[0].expr, Expr::Case { .. }));
+            assert_eq!(
+                "CASE WHEN a IS NULL THEN 1 ELSE 0 END",
+                order_by[0].expr.to_string()
+            );
+            assert_eq!(Expr::Identifier(Ident::new("b")), order_by[1].expr);
+        }
+        _ => unreachable!(),
+    }
+
+    snowflake().verified_stmt(
+        "SELECT ROW_NUMBER() OVER (PARTITION BY c ORDER BY CASE a WHEN 'x' THEN 1 END DESC NULLS LAST, b) FROM t",
+    );
+}
+
+#[test]
+fn test_snowflake_create_user_with_equals_spacing() {
+    snowflake().one_statement_parses_to(
+        "CREATE USER u PASSWORD = 'x' DEFAULT_ROLE = r",
+        "CREATE USER u PASSWORD='x' DEFAULT_ROLE=r",
+    );
+}
+
+#[test]
+fn test_snowflake_alter_user() {
+    match snowflake().verified_stmt("ALTER USER u SET DEFAULT_ROLE=r PASSWORD='x'") {
+        Statement::AlterUser(AlterUser {
+            if_exists,
+            name,
+            operation,
+        }) => {
+            assert!(!if_exists);
+            assert_eq!(name, Ident::new("u"));
+            assert_eq!(
+                operation,
+                AlterUserOperation::Set(KeyValueOptions {
+                    delimiter: KeyValueOptionsDelimiter::Space,
+                    options: vec![
+                        KeyValueOption {
+                            option_name: "DEFAULT_ROLE".to_string(),
+                            option_type: KeyValueOptionType::ENUM,
+                            value: "r".to_string(),
+                        },
+                        KeyValueOption {
+                            option_name: "PASSWORD".to_string(),
+                            option_type: KeyValueOptionType::STRING,
+                            value: "x".to_string(),
+                        },
+                    ],
+                })
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    match snowflake().verified_stmt("ALTER USER IF EXISTS u UNSET DEFAULT_ROLE, DISPLAY_NAME") {
+        Statement::AlterUser(AlterUser {
+            if_exists,
+            operation,
+            ..
+        }) => {
+            assert!(if_exists);
+            assert_eq!(
+                operation,
+                AlterUserOperation::Unset(vec![
+                    Ident::new("DEFAULT_ROLE"),
+                    Ident::new("DISPLAY_NAME")
+                ])
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    snowflake().verified_stmt("ALTER USER u RENAME TO v");
+    snowflake().one_statement_parses_to(
+        "ALTER USER u SET MUST_CHANGE_PASSWORD = TRUE",
+        "ALTER USER u SET MUST_CHANGE_PASSWORD=TRUE",
+    );
+
+    assert_eq!(
+        snowflake()
+            .parse_sql_statements("ALTER USER u")
+            .unwrap_err()
+            .to_string(),
+        "sql parser error: Expected: RENAME, SET or UNSET after ALTER USER, found: EOF"
+    );
+}
+
+#[test]
+fn test_insert_all_multi_table() {
+    let sql = "INSERT ALL INTO t1 INTO t2 (c1, c2) VALUES (a, NULL) SELECT a, b FROM src";
+    match snowflake().verified_stmt(sql) {
+        Statement::InsertMultiple(InsertMultiple {
+            overwrite,
+            kind,
+            into_clauses,
+            when_clauses,
+            else_clauses,
+            source,
+        }) => {
+            assert!(!overwrite);
+            assert_eq!(kind, InsertMultipleKind::All);
+            assert_eq!(
+                into_clauses,
+                vec![
+                    InsertMultipleIntoClause {
+                        table_name: ObjectName::from(vec![Ident::new("t1")]),
+                        columns: vec![],
+                        values: None,
+                    },
+                    InsertMultipleIntoClause {
+                        table_name: ObjectName::from(vec![Ident::new("t2")]),
+                        columns: vec![Ident::new("c1"), Ident::new("c2")],
+                        values: Some(vec![
+                            Expr::Identifier(Ident::new("a")),
+                            Expr::Value(Value::Null.with_empty_span()),
+                        ]),
+                    },
+                ]
+            );
+            assert!(when_clauses.is_empty());
+            assert_eq!(else_clauses, None);
+            assert_eq!(source.to_string(), "SELECT a, b FROM src");
+        }
+        _ => unreachable!(),
+    }
+
+    snowflake().verified_stmt(
+        "INSERT OVERWRITE ALL WHEN a > 1 THEN INTO t1 INTO t2 WHEN a < 0 THEN INTO t3 ELSE INTO t4 SELECT a FROM src",
+    );
+    snowflake().verified_stmt("INSERT ALL INTO t1 (SELECT a FROM src)");
+    // plain inserts are unaffected
+    snowflake().verified_stmt("INSERT OVERWRITE INTO t1 SELECT a FROM src");
+}
+
+#[test]
+fn test_insert_first_multi_table() {
+    let sql = "INSERT FIRST WHEN a > 1 THEN INTO t1 VALUES (a) ELSE INTO t2 SELECT a FROM src";
+    match snowflake().verified_stmt(sql) {
+        Statement::InsertMultiple(InsertMultiple {
+            kind,
+            into_clauses,
+            when_clauses,
+            else_clauses,
+            ..
+        }) => {
+            assert_eq!(kind, InsertMultipleKind::First);
+            assert!(into_clauses.is_empty());
+            assert_eq!(
+                when_clauses,
+                vec![InsertMultipleWhenClause {
+                    condition: Expr::BinaryOp {
+                        left: Box::new(Expr::Identifier(Ident::new("a"))),
+                        op: BinaryOperator::Gt,
+                        right: Box::new(Expr::value(number("1"))),
+                    },
+                    into_clauses: vec![InsertMultipleIntoClause {
+                        table_name: ObjectName::from(vec![Ident::new("t1")]),
+                        columns: vec![],
+                        values: Some(vec![Expr::Identifier(Ident::new("a"))]),
+                    }],
+                }]
+            );
+            assert_eq!(
+                else_clauses,
+                Some(vec![InsertMultipleIntoClause {
+                    table_name: ObjectName::from(vec![Ident::new("t2")]),
+                    columns: vec![],
+                    values: None,
+                }])
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    assert_eq!(
+        snowflake()
+            .parse_sql_statements("INSERT FIRST INTO t1 SELECT a FROM src")
+            .unwrap_err()
+            .to_string(),
+        "sql parser error: Expected: WHEN, found: INTO"
+    );
+}
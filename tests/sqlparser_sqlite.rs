@@ -125,6 +125,54 @@ fn pragma_eq_placeholder_style() {
     }
 }
 
+#[test]
+fn pragma_unquoted_values() {
+    match sqlite_and_generic().verified_stmt("PRAGMA foreign_keys = ON") {
+        Statement::Pragma {
+            name,
+            value: Some(val),
+            is_eq: true,
+        } => {
+            assert_eq!("foreign_keys", name.to_string());
+            assert_eq!(Value::Placeholder("ON".to_string()), val);
+        }
+        _ => unreachable!(),
+    }
+
+    match sqlite_and_generic().verified_stmt("PRAGMA main.table_info(t)") {
+        Statement::Pragma {
+            name,
+            value: Some(val),
+            is_eq: false,
+        } => {
+            assert_eq!("main.table_info", name.to_string());
+            assert_eq!(Value::Placeholder("t".to_string()), val);
+        }
+        _ => unreachable!(),
+    }
+
+    match sqlite_and_generic().verified_stmt("PRAGMA cache_size = -2000") {
+        Statement::Pragma {
+            value: Some(val), ..
+        } => {
+            assert_eq!(number("-2000"), val);
+        }
+        _ => unreachable!(),
+    }
+
+    match sqlite_and_generic().verified_stmt("PRAGMA table_info('t')") {
+        Statement::Pragma {
+            value: Some(val), ..
+        } => {
+            assert_eq!(Value::SingleQuotedString("t".to_string()), val);
+        }
+        _ => unreachable!(),
+    }
+
+    sqlite_and_generic().verified_stmt("PRAGMA journal_mode = WAL");
+    sqlite_and_generic().verified_stmt("PRAGMA foreign_keys = false");
+}
+
 #[test]
 fn parse_create_table_without_rowid() {
     let sql = "CREATE TABLE t (a INT) WITHOUT ROWID";
@@ -426,6 +474,7 @@ fn parse_window_function_with_filter() {
                     ))],
                     clauses: vec![],
                 }),
+                from_first: None,
                 null_treatment: None,
                 over: Some(WindowType::WindowSpec(WindowSpec {
                     window_name: None,
@@ -462,6 +511,27 @@ fn parse_attach_database() {
     }
 }
 
+#[test]
+fn parse_detach_database() {
+    match sqlite().verified_stmt("DETACH DATABASE test") {
+        Statement::DetachDatabase {
+            schema_name,
+            database,
+        } => {
+            assert_eq!(schema_name, Ident::new("test"));
+            assert!(database);
+        }
+        _ => unreachable!(),
+    }
+
+    match sqlite().verified_stmt("DETACH test") {
+        Statement::DetachDatabase { database, .. } => assert!(!database),
+        _ => unreachable!(),
+    }
+
+    sqlite().verified_stmt("ATTACH 'test.db' AS test");
+}
+
 #[test]
 fn parse_update_tuple_row_values() {
     // See https://github.com/sqlparser-rs/sqlparser-rs/issues/1311